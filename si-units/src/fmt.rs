@@ -234,6 +234,7 @@ const WEBER: SINumber = SINumber::new(1.0, _WEBER);
 const TESLA: SINumber = SINumber::new(1.0, _TESLA);
 const HENRY: SINumber = SINumber::new(1.0, _HENRY);
 const CANDELA: SINumber = SINumber::new(1.0, _CANDELA);
+const LUX: SINumber = SINumber::new(1.0, _LUX);
 const SQUARE_SECOND: SINumber = SINumber::new(1.0, _SECOND.powi(2));
 
 static DERIVED_UNIT_SYMBOLS: LazyLock<HashMap<&'static str, (SINumber, Option<f64>)>> =
@@ -261,6 +262,7 @@ static DERIVED_UNIT_SYMBOLS: LazyLock<HashMap<&'static str, (SINumber, Option<f6
         m.insert("T", (TESLA, Some(PETA)));
         m.insert("H", (HENRY, Some(PETA)));
         m.insert("lm", (CANDELA, None));
+        m.insert("lx", (LUX, Some(MEGA)));
         m.insert("s²", (SQUARE_SECOND, None));
         m
     });
@@ -269,7 +271,7 @@ type SIUnitSymbol = (SINumber, String, Option<f64>, Vec<&'static str>, Vec<i8>);
 
 fn insert_derived_unit(map: &mut HashMap<SIUnit, SIUnitSymbol>, s: &'static str) {
     let u_reg = Regex::new("([\\*/])").unwrap();
-    let o_reg = Regex::new("mol|m³|m²|m|g|kg|s²|s|K|Hz|N|Pa|J|Wb|W|C|V|F|Ω|S|T|H|lm").unwrap();
+    let o_reg = Regex::new("mol|m³|m²|m|g|kg|s²|s|K|Hz|N|Pa|J|Wb|W|C|V|F|Ω|S|T|H|lm|lx").unwrap();
     let mut unit = SINumber::new(1.0, SIUnit::DIMENSIONLESS);
     let mut has_prefix = None;
     let mut symbols = Vec::new();
@@ -330,6 +332,8 @@ static DERIVED_UNITS: LazyLock<HashMap<SIUnit, SIUnitSymbol>> = LazyLock::new(||
     insert_derived_unit(&mut m, "Wb");
     insert_derived_unit(&mut m, "T");
     insert_derived_unit(&mut m, "H");
+    insert_derived_unit(&mut m, "lx");
+    insert_derived_unit(&mut m, "mol/s");
     insert_derived_unit(&mut m, "mol/m³");
     insert_derived_unit(&mut m, "mol/m²");
     insert_derived_unit(&mut m, "mol/m");
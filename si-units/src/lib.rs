@@ -429,6 +429,9 @@ const _TESLA: SIUnit = SIUnit([0, 1, -2, -1, 0, 0, 0]);
 const _HENRY: SIUnit = SIUnit([2, 1, -2, -2, 0, 0, 0]);
 const _METER_PER_SECOND: SIUnit = SIUnit([1, 0, -1, 0, 0, 0, 0]);
 const _LUMEN_PER_WATT: SIUnit = SIUnit([-2, -1, 3, 0, 0, 0, 1]);
+const _LUX: SIUnit = SIUnit([-2, 0, 0, 0, 0, 0, 1]);
+const _GRAY: SIUnit = SIUnit([2, 0, -2, 0, 0, 0, 0]);
+const _KATAL: SIUnit = SIUnit([0, 0, -1, 0, 1, 0, 0]);
 
 /// Prefix quecto $\\left(\text{q}=10^{-30}\\right)$
 pub const QUECTO: f64 = 1e-30;
@@ -518,6 +521,13 @@ pub fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     add_constant(m, "WEBER", 1.0, _WEBER)?;
     add_constant(m, "TESLA", 1.0, _TESLA)?;
     add_constant(m, "HENRY", 1.0, _HENRY)?;
+    m.add("STERADIAN", 1.0)?;
+    add_constant(m, "LUMEN", 1.0, _CANDELA)?;
+    add_constant(m, "LUX", 1.0, _LUX)?;
+    add_constant(m, "BECQUEREL", 1.0, _HERTZ)?;
+    add_constant(m, "GRAY", 1.0, _GRAY)?;
+    add_constant(m, "SIEVERT", 1.0, _GRAY)?;
+    add_constant(m, "KATAL", 1.0, _KATAL)?;
 
     add_constant(m, "ANGSTROM", 1e-10, _METER)?;
     add_constant(m, "AMU", 1.6605390671738466e-27, _KILOGRAM)?;
@@ -56,6 +56,13 @@ from si_units._core import (
     WEBER,
     TESLA,
     HENRY,
+    STERADIAN,
+    LUMEN,
+    LUX,
+    BECQUEREL,
+    GRAY,
+    SIEVERT,
+    KATAL,
     ANGSTROM,
     AMU,
     AU,
@@ -136,6 +143,13 @@ __all__ = [
     "WEBER",
     "TESLA",
     "HENRY",
+    "STERADIAN",
+    "LUMEN",
+    "LUX",
+    "BECQUEREL",
+    "GRAY",
+    "SIEVERT",
+    "KATAL",
     "ANGSTROM",
     "AMU",
     "AU",
@@ -241,6 +241,14 @@ SIEMENS: Final[SIObject]
 WEBER: Final[SIObject]
 TESLA: Final[SIObject]
 HENRY: Final[SIObject]
+# dimensionless quantities are represented by floats
+STERADIAN: Final[float]
+LUMEN: Final[SIObject]
+LUX: Final[SIObject]
+BECQUEREL: Final[SIObject]
+GRAY: Final[SIObject]
+SIEVERT: Final[SIObject]
+KATAL: Final[SIObject]
 ANGSTROM: Final[SIObject]
 AMU: Final[SIObject]
 AU: Final[SIObject]
@@ -1,5 +1,6 @@
+use super::state::{StateGradient, StateJacobian, StateVector};
 use super::{Diff, Quantity};
-use nalgebra::{DefaultAllocator, Dim, OMatrix, OVector, U1, allocator::Allocator};
+use nalgebra::{Const, DefaultAllocator, Dim, OMatrix, OVector, U1, allocator::Allocator};
 use num_dual::{
     Dual, Dual2, Dual2Vec, Dual3, DualNum, DualStruct, DualVec, Gradients, HyperDual, HyperDualVec,
     HyperHyperDual, Real,
@@ -102,6 +103,81 @@ where
     (Quantity::new(r.0), Quantity::new(r.1))
 }
 
+/// Calculate the gradient of a scalar function of a heterogeneous [StateVector].
+///
+/// The partial derivatives are returned as a tuple with the units of the function value
+/// divided by the units of the respective components of the state.
+///
+/// # Example
+/// ```
+/// # use quantity::*;
+/// # use quantity::ad::state_gradient;
+/// # use num_dual::DualNum;
+/// # use approx::assert_relative_eq;
+/// fn pressure<D: DualNum<Primitive = f64> + Copy>((t, v): (Temperature<D>, Volume<D>)) -> Pressure<D> {
+///     t * MOL * RGAS / v
+/// }
+/// let (p, (dp_dt, dp_dv)) = state_gradient(pressure, &(300.0 * KELVIN, 25.0 * LITER));
+/// assert_relative_eq!(p, MOL * RGAS * 300.0 * KELVIN / (25.0 * LITER));
+/// assert_relative_eq!(dp_dt, p / (300.0 * KELVIN));
+/// assert_relative_eq!(dp_dv, -p / (25.0 * LITER));
+/// ```
+pub fn state_gradient<G, T: DualNum, X, UY, const N: usize>(
+    g: G,
+    x: &X,
+) -> (Quantity<T, UY>, <UY as StateGradient<X, N>>::Output)
+where
+    X: StateVector<N, Inner = T>,
+    UY: StateGradient<X, N>,
+    G: Fn(X::Map<DualVec<T, Const<N>>>) -> Quantity<DualVec<T, Const<N>>, UY>,
+{
+    let (f, grad) = num_dual::gradient(
+        |x| g(X::Map::<DualVec<T, Const<N>>>::from_vector(&x)).0,
+        &x.to_vector(),
+    );
+    (Quantity::new(f), UY::gradient_from_vector(&grad))
+}
+
+/// Calculate the Jacobian of a function that maps a heterogeneous [StateVector] to
+/// another heterogeneous state vector.
+///
+/// The Jacobian is returned as a tuple of rows, each of which is a tuple of the partial
+/// derivatives of one component of the function value with the correct units.
+///
+/// # Example
+/// ```
+/// # use quantity::*;
+/// # use quantity::ad::state_jacobian;
+/// # use num_dual::DualNum;
+/// # use approx::assert_relative_eq;
+/// fn ideal_gas<D: DualNum<Primitive = f64> + Copy>(
+///     (t, v): (Temperature<D>, Volume<D>),
+/// ) -> (Pressure<D>, Energy<D>) {
+///     (t * MOL * RGAS / v, t * 1.5 * MOL * RGAS)
+/// }
+/// let ((p, u), ((dp_dt, dp_dv), (du_dt, du_dv))) =
+///     state_jacobian(ideal_gas, &(300.0 * KELVIN, 25.0 * LITER));
+/// assert_relative_eq!(dp_dt, p / (300.0 * KELVIN));
+/// assert_relative_eq!(dp_dv, -p / (25.0 * LITER));
+/// assert_relative_eq!(du_dt, 1.5 * MOL * RGAS);
+/// assert_relative_eq!(du_dv, 0.0 * PASCAL);
+/// ```
+pub fn state_jacobian<G, T: DualNum, X, Y, const N: usize, const M: usize>(
+    g: G,
+    x: &X,
+) -> (Y::Map<T>, <Y as StateJacobian<X, N, M>>::Output)
+where
+    X: StateVector<N, Inner = T>,
+    Y: StateVector<M, Inner = DualVec<T, Const<N>>> + StateJacobian<X, N, M>,
+    G: Fn(X::Map<DualVec<T, Const<N>>>) -> Y,
+{
+    let (f, jac) = num_dual::jacobian(
+        |x| g(X::Map::<DualVec<T, Const<N>>>::from_vector(&x)).to_vector(),
+        &x.to_vector(),
+    );
+    (Y::Map::<T>::from_vector(&f), Y::jacobian_from_matrix(&jac))
+}
+
 #[expect(clippy::type_complexity)]
 pub fn second_derivative<G, T: DualNum, UX, UY>(
     g: G,
@@ -421,4 +497,18 @@ mod test_num_dual {
             ])
         );
     }
+
+    fn box_volume<D: DualNum + Copy>((x, y, h): (Length<D>, Length<D>, Length<D>)) -> (Volume<D>,) {
+        (x * y * h,)
+    }
+
+    #[test]
+    fn test_state_jacobian() {
+        let ((v,), ((dv_dx, dv_dy, dv_dh),)) =
+            state_jacobian(box_volume, &(2.0 * METER, 3.0 * METER, 4.0 * METER));
+        assert_eq!(v, 24.0 * METER.powi::<3>());
+        assert_eq!(dv_dx, 12.0 * METER.powi::<2>());
+        assert_eq!(dv_dy, 8.0 * METER.powi::<2>());
+        assert_eq!(dv_dh, 6.0 * METER.powi::<2>());
+    }
 }
@@ -0,0 +1,131 @@
+//! Strategies for property-based testing with [proptest].
+//!
+//! Scalar quantities implement [Arbitrary]. By default, arbitrary finite values
+//! (in SI units) are generated. Ranges in physical units can be passed as parameters
+//! or by using the [range] and [range_inclusive] strategies.
+//!
+//! # Example
+//! ```
+//! # use quantity::{Pressure, KELVIN, BAR, MOL, METER, RGAS};
+//! # use quantity::arbitrary::range;
+//! # use proptest::prelude::*;
+//! proptest! {
+//!     fn ideal_gas_pressure_is_positive(
+//!         t in range(200.0 * KELVIN..600.0 * KELVIN),
+//!         p in any_with::<Pressure>(Some(0.1 * BAR..100.0 * BAR)),
+//!     ) {
+//!         let rho = p / (RGAS * t);
+//!         prop_assert!(rho > 0.0 * MOL / METER.powi::<3>());
+//!     }
+//! }
+//! # ideal_gas_pressure_is_positive();
+//! ```
+use super::{Angle, Quantity};
+#[cfg(feature = "ndarray")]
+use ndarray::Array1;
+use proptest::arbitrary::Arbitrary;
+#[cfg(feature = "ndarray")]
+use proptest::collection::{SizeRange, vec};
+use proptest::strategy::{BoxedStrategy, Strategy};
+use std::f64::consts::TAU;
+use std::fmt::Debug;
+use std::ops::{Range, RangeInclusive};
+
+/// Generate quantities uniformly distributed in the half-open range `range`.
+pub fn range<U>(range: Range<Quantity<f64, U>>) -> impl Strategy<Value = Quantity<f64, U>>
+where
+    Quantity<f64, U>: Debug,
+{
+    (range.start.0..range.end.0).prop_map(Quantity::new)
+}
+
+/// Generate quantities uniformly distributed in the closed range `range`.
+pub fn range_inclusive<U>(
+    range: RangeInclusive<Quantity<f64, U>>,
+) -> impl Strategy<Value = Quantity<f64, U>>
+where
+    Quantity<f64, U>: Debug,
+{
+    let (start, end) = range.into_inner();
+    (start.0..=end.0).prop_map(Quantity::new)
+}
+
+/// Generate angles uniformly distributed in the range [0, 2π).
+pub fn angle() -> impl Strategy<Value = Angle> {
+    (0.0..TAU).prop_map(Quantity::new)
+}
+
+/// Generate one-dimensional quantity arrays with a length in `size`, whose elements
+/// are generated by `element`.
+///
+/// # Example
+/// ```
+/// # use quantity::KELVIN;
+/// # use quantity::arbitrary::{array1, range};
+/// # use proptest::prelude::*;
+/// proptest! {
+///     fn mean_is_bounded(t in array1(range(200.0 * KELVIN..600.0 * KELVIN), 1..20)) {
+///         let mean = t.mean().unwrap();
+///         prop_assert!(mean >= t.min().unwrap() && mean <= t.max().unwrap());
+///     }
+/// }
+/// # mean_is_bounded();
+/// ```
+#[cfg(feature = "ndarray")]
+pub fn array1<U, S>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = Quantity<Array1<f64>, U>>
+where
+    S: Strategy<Value = Quantity<f64, U>>,
+    Quantity<Array1<f64>, U>: Debug,
+{
+    vec(element, size).prop_map(|v| v.into_iter().collect())
+}
+
+impl<U: 'static> Arbitrary for Quantity<f64, U>
+where
+    Self: Debug,
+{
+    /// An optional range of the generated values.
+    type Parameters = Option<Range<Self>>;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        match args {
+            Some(range) => (range.start.0..range.end.0).prop_map(Quantity::new).boxed(),
+            None => (proptest::num::f64::NORMAL | proptest::num::f64::ZERO)
+                .prop_map(Quantity::new)
+                .boxed(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DEGREES, KELVIN, Temperature};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_range(t in range(200.0 * KELVIN..600.0 * KELVIN)) {
+            prop_assert!(t.0 >= 200.0 && t.0 < 600.0);
+        }
+
+        #[test]
+        fn test_range_inclusive(a in range_inclusive(0.0 * DEGREES..=90.0 * DEGREES)) {
+            prop_assert!(a.0 >= 0.0 && a.0 <= 90.0f64.to_radians());
+        }
+
+        #[test]
+        fn test_angle(a in angle()) {
+            prop_assert!(a.sin().abs() <= 1.0);
+        }
+
+        #[test]
+        fn test_arbitrary(t in any::<Temperature>(), a in any::<Angle>()) {
+            prop_assert!(t.0.is_finite() && a.0.is_finite());
+        }
+    }
+}
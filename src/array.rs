@@ -1,13 +1,14 @@
-use super::Quantity;
+use super::{Const, Diff, Prod, Quantity, Sum};
 use ndarray::iter::LanesMut;
+use ndarray::linalg::Dot;
 use ndarray::{
-    Array, Array1, ArrayBase, ArrayView, Axis, Data, DataMut, Dimension, IxDyn, NdIndex,
-    RemoveAxis, ShapeBuilder, ShapeError,
+    Array, Array1, Array2, ArrayBase, ArrayView, ArrayView1, ArrayViewMut, ArrayViewMut1, Axis,
+    Data, DataMut, Dimension, IntoDimension, Ix1, IxDyn, LinalgScalar, NdIndex, RemoveAxis,
+    ShapeBuilder, ShapeError, SliceArg, Zip,
 };
-use num_traits::Zero;
+use num_traits::{Float, FromPrimitive, One, Zero};
 use std::iter::FromIterator;
-use std::marker::PhantomData;
-use std::ops::{Add, Mul, Sub};
+use std::ops::{Add, Div, Mul, Sub};
 
 impl<T: Copy, U> Quantity<Array1<T>, U> {
     /// Create a one-dimensional array from a vector of scalar quantities.
@@ -54,6 +55,356 @@ impl<U> Quantity<Array1<f64>, U> {
     }
 }
 
+impl<T: LinalgScalar, S: Data<Elem = T>, U> Quantity<ArrayBase<S, Ix1>, U> {
+    /// Return the outer product of two one-dimensional arrays.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{KELVIN, MOL, SECOND};
+    /// # use ndarray::{arr1, arr2};
+    /// # use approx::assert_relative_eq;
+    /// let n = arr1(&[1.0, 2.0]) * MOL;
+    /// let t = arr1(&[300.0, 400.0, 500.0]) * KELVIN;
+    /// assert_relative_eq!(
+    ///     n.outer(&t),
+    ///     arr2(&[[300.0, 400.0, 500.0], [600.0, 800.0, 1000.0]]) * MOL * KELVIN
+    /// );
+    /// ```
+    pub fn outer<S2: Data<Elem = T>, U2>(
+        &self,
+        rhs: &Quantity<ArrayBase<S2, Ix1>, U2>,
+    ) -> Quantity<Array2<T>, Sum<U, U2>>
+    where
+        U: Add<U2>,
+    {
+        let lhs = self.0.view().insert_axis(Axis(1));
+        let rhs = rhs.0.view().insert_axis(Axis(0));
+        Quantity::new(lhs.dot(&rhs))
+    }
+}
+
+impl<S: Data<Elem = f64>, U> Quantity<ArrayBase<S, Ix1>, U> {
+    /// Integrate the array over the sampling points `x` using the trapezoidal rule.
+    ///
+    /// **Panics** if `x` and `self` have different lengths.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Energy, Pressure, Volume, BAR, JOULE, METER};
+    /// # use ndarray::{arr1, Array1};
+    /// # use approx::assert_relative_eq;
+    /// let v = Volume::<Array1<f64>>::linspace(1.0 * METER.powi::<3>(), 3.0 * METER.powi::<3>(), 3);
+    /// let p = arr1(&[3.0, 2.0, 2.0]) * BAR;
+    /// let w: Energy = p.trapz(&v);
+    /// assert_relative_eq!(w, 4.5e5 * JOULE);
+    /// ```
+    pub fn trapz<S2: Data<Elem = f64>, UX>(
+        &self,
+        x: &Quantity<ArrayBase<S2, Ix1>, UX>,
+    ) -> Quantity<f64, Sum<U, UX>>
+    where
+        U: Add<UX>,
+    {
+        Quantity::new(trapz(self.0.view(), x.0.view()))
+    }
+
+    /// Integrate the array over the sampling points `x` using Simpson's rule.
+    ///
+    /// The sampling points do not have to be evenly spaced. For an odd number of
+    /// intervals, the last interval is integrated using a quadratic interpolation
+    /// of the last three points.
+    ///
+    /// **Panics** if `x` and `self` have different lengths.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Length, Area, METER};
+    /// # use ndarray::Array1;
+    /// # use approx::assert_relative_eq;
+    /// let x = Length::<Array1<f64>>::linspace(0.0 * METER, 3.0 * METER, 4);
+    /// let y = x.mapv(|x| x * x / METER);
+    /// let a: Area = y.simpson(&x);
+    /// assert_relative_eq!(a, 9.0 * METER * METER, max_relative = 1e-14 * METER * METER);
+    /// ```
+    pub fn simpson<S2: Data<Elem = f64>, UX>(
+        &self,
+        x: &Quantity<ArrayBase<S2, Ix1>, UX>,
+    ) -> Quantity<f64, Sum<U, UX>>
+    where
+        U: Add<UX>,
+    {
+        Quantity::new(simpson(self.0.view(), x.0.view()))
+    }
+
+    /// Return the cumulative integral of the array over the sampling points `x` using the
+    /// trapezoidal rule.
+    ///
+    /// The result has the same length as `self` and starts at zero.
+    ///
+    /// **Panics** if `x` and `self` have different lengths.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Length, METER, SECOND};
+    /// # use ndarray::arr1;
+    /// # use approx::assert_relative_eq;
+    /// let t = arr1(&[0.0, 1.0, 2.0]) * SECOND;
+    /// let v = arr1(&[2.0, 2.0, 4.0]) * METER / SECOND;
+    /// let x = v.cumulative_trapz(&t);
+    /// assert_relative_eq!(x, arr1(&[0.0, 2.0, 5.0]) * METER);
+    /// ```
+    pub fn cumulative_trapz<S2: Data<Elem = f64>, UX>(
+        &self,
+        x: &Quantity<ArrayBase<S2, Ix1>, UX>,
+    ) -> Quantity<Array1<f64>, Sum<U, UX>>
+    where
+        U: Add<UX>,
+    {
+        self.cumulative_trapz_axis(x, Axis(0))
+    }
+
+    /// Return the derivative of the array with respect to the sampling points `x`.
+    ///
+    /// Second order accurate central differences are used in the interior and second order
+    /// accurate one-sided differences at the boundaries. The sampling points do not have to
+    /// be evenly spaced.
+    ///
+    /// **Panics** if `x` and `self` have different lengths or if there are less than two values.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Length, METER};
+    /// # use ndarray::{arr1, Array1};
+    /// # use approx::assert_relative_eq;
+    /// let x = arr1(&[0.0, 1.0, 3.0, 4.0]) * METER;
+    /// let a = x.mapv(|x| x * x);
+    /// assert_relative_eq!(a.gradient(&x), x.mapv(|x| 2.0 * x), epsilon = 1e-14 * METER);
+    /// ```
+    pub fn gradient<S2: Data<Elem = f64>, UX>(
+        &self,
+        x: &Quantity<ArrayBase<S2, Ix1>, UX>,
+    ) -> Quantity<Array1<f64>, Diff<U, UX>>
+    where
+        U: Sub<UX>,
+    {
+        self.gradient_axis(x, Axis(0))
+    }
+}
+
+impl<S: Data<Elem = f64>, U, D: Dimension> Quantity<ArrayBase<S, D>, U> {
+    /// Integrate the array along `axis` over the sampling points `x` using the trapezoidal rule.
+    ///
+    /// **Panics** if the length of `x` does not match the length of the axis.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Length, METER, PASCAL, NEWTON};
+    /// # use ndarray::{arr1, arr2, Array1, Axis};
+    /// # use approx::assert_relative_eq;
+    /// let x = Length::<Array1<f64>>::linspace(0.0 * METER, 2.0 * METER, 3);
+    /// let p = arr2(&[[1.0, 2.0, 3.0], [2.0, 2.0, 2.0]]) * PASCAL;
+    /// let f = p.trapz_axis(&x, Axis(1));
+    /// assert_relative_eq!(f, arr1(&[4.0, 4.0]) * NEWTON / METER);
+    /// ```
+    pub fn trapz_axis<S2: Data<Elem = f64>, UX>(
+        &self,
+        x: &Quantity<ArrayBase<S2, Ix1>, UX>,
+        axis: Axis,
+    ) -> Quantity<Array<f64, D::Smaller>, Sum<U, UX>>
+    where
+        U: Add<UX>,
+        D: RemoveAxis,
+    {
+        Quantity::new(self.0.map_axis(axis, |y| trapz(y, x.0.view())))
+    }
+
+    /// Integrate the array along `axis` over the sampling points `x` using Simpson's rule.
+    ///
+    /// **Panics** if the length of `x` does not match the length of the axis.
+    pub fn simpson_axis<S2: Data<Elem = f64>, UX>(
+        &self,
+        x: &Quantity<ArrayBase<S2, Ix1>, UX>,
+        axis: Axis,
+    ) -> Quantity<Array<f64, D::Smaller>, Sum<U, UX>>
+    where
+        U: Add<UX>,
+        D: RemoveAxis,
+    {
+        Quantity::new(self.0.map_axis(axis, |y| simpson(y, x.0.view())))
+    }
+
+    /// Return the cumulative integral of the array along `axis` over the sampling points
+    /// `x` using the trapezoidal rule.
+    ///
+    /// The result has the same shape as `self` and starts at zero along `axis`.
+    ///
+    /// **Panics** if the length of `x` does not match the length of the axis.
+    pub fn cumulative_trapz_axis<S2: Data<Elem = f64>, UX>(
+        &self,
+        x: &Quantity<ArrayBase<S2, Ix1>, UX>,
+        axis: Axis,
+    ) -> Quantity<Array<f64, D>, Sum<U, UX>>
+    where
+        U: Add<UX>,
+    {
+        let mut res = Array::zeros(self.0.raw_dim());
+        Zip::from(res.lanes_mut(axis))
+            .and(self.0.lanes(axis))
+            .for_each(|mut res, y| {
+                check_samples(y.len(), x.len());
+                let mut integral = 0.0;
+                for i in 1..y.len() {
+                    integral += 0.5 * (x.0[i] - x.0[i - 1]) * (y[i] + y[i - 1]);
+                    res[i] = integral;
+                }
+            });
+        Quantity::new(res)
+    }
+
+    /// Return the derivative of the array along `axis` with respect to the sampling points `x`.
+    ///
+    /// Second order accurate central differences are used in the interior and second order
+    /// accurate one-sided differences at the boundaries. The sampling points do not have to
+    /// be evenly spaced.
+    ///
+    /// **Panics** if the length of `x` does not match the length of the axis or if there are
+    /// less than two values along the axis.
+    pub fn gradient_axis<S2: Data<Elem = f64>, UX>(
+        &self,
+        x: &Quantity<ArrayBase<S2, Ix1>, UX>,
+        axis: Axis,
+    ) -> Quantity<Array<f64, D>, Diff<U, UX>>
+    where
+        U: Sub<UX>,
+    {
+        let mut res = Array::zeros(self.0.raw_dim());
+        Zip::from(res.lanes_mut(axis))
+            .and(self.0.lanes(axis))
+            .for_each(|res, y| gradient(y, x.0.view(), res));
+        Quantity::new(res)
+    }
+
+    /// Return the derivative of the array along `axis` for evenly spaced sampling points
+    /// with distance `dx`.
+    ///
+    /// **Panics** if there are less than two values along the axis.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{KELVIN, METER};
+    /// # use ndarray::{arr1, arr2, Axis};
+    /// # use approx::assert_relative_eq;
+    /// let t = arr2(&[[300.0, 310.0, 330.0], [300.0, 300.0, 300.0]]) * KELVIN;
+    /// let dt = t.gradient_uniform(0.5 * METER, Axis(1));
+    /// assert_relative_eq!(dt.index_axis(Axis(0), 0).to_owned(), arr1(&[10.0, 30.0, 50.0]) * KELVIN / METER);
+    /// ```
+    pub fn gradient_uniform<UX>(
+        &self,
+        dx: Quantity<f64, UX>,
+        axis: Axis,
+    ) -> Quantity<Array<f64, D>, Diff<U, UX>>
+    where
+        U: Sub<UX>,
+    {
+        let n = self.0.len_of(axis);
+        let x = Array1::from_shape_fn(n, |i| i as f64 * dx.0);
+        self.gradient_axis(&Quantity::<_, UX>::new(x), axis)
+    }
+
+    /// Return the weighted mean of all elements in the array.
+    ///
+    /// The weights can be arbitrary quantities (e.g., masses or mole numbers) and have to
+    /// have the same shape as `self`.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{KELVIN, KILOGRAM};
+    /// # use ndarray::arr1;
+    /// # use approx::assert_relative_eq;
+    /// let t = arr1(&[300.0, 400.0]) * KELVIN;
+    /// let m = arr1(&[3.0, 1.0]) * KILOGRAM;
+    /// assert_relative_eq!(t.weighted_mean(&m), 325.0 * KELVIN);
+    /// ```
+    pub fn weighted_mean<S2: Data<Elem = f64>, U2>(
+        &self,
+        weights: &Quantity<ArrayBase<S2, D>, U2>,
+    ) -> Quantity<f64, U> {
+        let weights = &weights.0;
+        Quantity::new((&self.0 * weights).sum() / weights.sum())
+    }
+}
+
+fn check_samples(n_y: usize, n_x: usize) {
+    assert_eq!(
+        n_y, n_x,
+        "The number of sampling points must match the number of values."
+    );
+}
+
+fn trapz(y: ArrayView1<'_, f64>, x: ArrayView1<'_, f64>) -> f64 {
+    check_samples(y.len(), x.len());
+    (1..y.len())
+        .map(|i| 0.5 * (x[i] - x[i - 1]) * (y[i] + y[i - 1]))
+        .sum()
+}
+
+fn simpson(y: ArrayView1<'_, f64>, x: ArrayView1<'_, f64>) -> f64 {
+    check_samples(y.len(), x.len());
+    let n = y.len();
+    if n < 3 {
+        return trapz(y, x);
+    }
+    let h = |i: usize| x[i + 1] - x[i];
+    let mut integral = 0.0;
+    for i in (0..n - 2).step_by(2) {
+        let (h0, h1) = (h(i), h(i + 1));
+        integral += (h0 + h1) / 6.0
+            * ((2.0 - h1 / h0) * y[i]
+                + (h0 + h1).powi(2) / (h0 * h1) * y[i + 1]
+                + (2.0 - h0 / h1) * y[i + 2]);
+    }
+    if n.is_multiple_of(2) {
+        // correction for the last interval for an odd number of intervals
+        let (h0, h1) = (h(n - 3), h(n - 2));
+        let alpha = (2.0 * h1 * h1 + 3.0 * h0 * h1) / (6.0 * (h0 + h1));
+        let beta = (h1 * h1 + 3.0 * h0 * h1) / (6.0 * h0);
+        let eta = h1 * h1 * h1 / (6.0 * h0 * (h0 + h1));
+        integral += alpha * y[n - 1] + beta * y[n - 2] - eta * y[n - 3];
+    }
+    integral
+}
+
+fn gradient(y: ArrayView1<'_, f64>, x: ArrayView1<'_, f64>, mut res: ArrayViewMut1<'_, f64>) {
+    check_samples(y.len(), x.len());
+    let n = y.len();
+    assert!(
+        n >= 2,
+        "At least two values are required to calculate a gradient."
+    );
+    if n == 2 {
+        let d = (y[1] - y[0]) / (x[1] - x[0]);
+        res.fill(d);
+        return;
+    }
+    for i in 1..n - 1 {
+        let (hs, hd) = (x[i] - x[i - 1], x[i + 1] - x[i]);
+        let a = -hd / (hs * (hs + hd));
+        let b = (hd - hs) / (hs * hd);
+        let c = hs / (hd * (hs + hd));
+        res[i] = a * y[i - 1] + b * y[i] + c * y[i + 1];
+    }
+    let (h1, h2) = (x[1] - x[0], x[2] - x[1]);
+    let a = -(2.0 * h1 + h2) / (h1 * (h1 + h2));
+    let b = (h1 + h2) / (h1 * h2);
+    let c = -h1 / (h2 * (h1 + h2));
+    res[0] = a * y[0] + b * y[1] + c * y[2];
+    let (h1, h2) = (x[n - 2] - x[n - 3], x[n - 1] - x[n - 2]);
+    let a = h2 / (h1 * (h1 + h2));
+    let b = -(h1 + h2) / (h1 * h2);
+    let c = (2.0 * h2 + h1) / (h2 * (h1 + h2));
+    res[n - 1] = a * y[n - 3] + b * y[n - 2] + c * y[n - 1];
+}
+
 impl<T, U, D: Dimension> Quantity<Array<T, D>, U> {
     /// Create an array with all elements set to 0.
     pub fn zeros<Sh: ShapeBuilder<Dim = D>>(shape: Sh) -> Self
@@ -63,6 +414,89 @@ impl<T, U, D: Dimension> Quantity<Array<T, D>, U> {
         Quantity::new(Array::zeros(shape))
     }
 
+    /// Concatenate arrays along `axis`.
+    ///
+    /// Errors if the arrays have mismatching shapes, apart from along `axis`, or if no
+    /// arrays are given.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Length, METER};
+    /// # use ndarray::{arr1, Array1, Axis};
+    /// # use approx::assert_relative_eq;
+    /// let x1 = arr1(&[1.0, 2.0]) * METER;
+    /// let x2 = arr1(&[3.0]) * METER;
+    /// let x = Length::<Array1<f64>>::concatenate(Axis(0), &[x1.view(), x2.view()]).unwrap();
+    /// assert_relative_eq!(x, arr1(&[1.0, 2.0, 3.0]) * METER);
+    /// ```
+    pub fn concatenate(
+        axis: Axis,
+        arrays: &[Quantity<ArrayView<'_, T, D>, U>],
+    ) -> Result<Self, ShapeError>
+    where
+        T: Clone,
+        D: RemoveAxis,
+    {
+        let arrays: Vec<_> = arrays.iter().map(|a| a.0.view()).collect();
+        ndarray::concatenate(axis, &arrays).map(Quantity::new)
+    }
+
+    /// Stack arrays along a new axis.
+    ///
+    /// Errors if the arrays have mismatching shapes or if no arrays are given.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Length, METER};
+    /// # use ndarray::{arr1, arr2, Axis};
+    /// # use approx::assert_relative_eq;
+    /// let x1 = arr1(&[1.0, 2.0]) * METER;
+    /// let x2 = arr1(&[3.0, 4.0]) * METER;
+    /// let x = Length::stack(Axis(0), &[x1.view(), x2.view()]).unwrap();
+    /// assert_relative_eq!(x, arr2(&[[1.0, 2.0], [3.0, 4.0]]) * METER);
+    /// ```
+    pub fn stack(
+        axis: Axis,
+        arrays: &[Quantity<ArrayView<'_, T, D>, U>],
+    ) -> Result<Quantity<Array<T, D::Larger>, U>, ShapeError>
+    where
+        T: Clone,
+        D::Larger: RemoveAxis,
+    {
+        let arrays: Vec<_> = arrays.iter().map(|a| a.0.view()).collect();
+        ndarray::stack(axis, &arrays).map(Quantity::new)
+    }
+
+    /// Append an array to `self` along `axis`.
+    ///
+    /// Errors if the shapes of the arrays do not match, apart from along `axis`.
+    pub fn append(
+        &mut self,
+        axis: Axis,
+        array: Quantity<ArrayView<'_, T, D>, U>,
+    ) -> Result<(), ShapeError>
+    where
+        T: Clone,
+        D: RemoveAxis,
+    {
+        self.0.append(axis, array.0)
+    }
+
+    /// Append a subview of one dimension less to `self` along `axis`.
+    ///
+    /// Errors if the shapes of the arrays do not match, apart from along `axis`.
+    pub fn push(
+        &mut self,
+        axis: Axis,
+        array: Quantity<ArrayView<'_, T, D::Smaller>, U>,
+    ) -> Result<(), ShapeError>
+    where
+        T: Clone,
+        D: RemoveAxis,
+    {
+        self.0.push(axis, array.0)
+    }
+
     /// Create an array with values created by the function f.
     pub fn from_shape_fn<Sh, F>(shape: Sh, mut f: F) -> Self
     where
@@ -73,6 +507,39 @@ impl<T, U, D: Dimension> Quantity<Array<T, D>, U> {
     }
 }
 
+impl<T, U> Quantity<Array2<T>, U> {
+    /// Append a row to a two-dimensional array.
+    ///
+    /// Errors if the length of the row does not match the number of columns.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Pressure, BAR};
+    /// # use ndarray::{arr1, arr2, Array2};
+    /// # use approx::assert_relative_eq;
+    /// let mut p = Pressure::zeros((0, 2));
+    /// p.push_row((arr1(&[1.0, 2.0]) * BAR).view()).unwrap();
+    /// p.push_row((arr1(&[3.0, 4.0]) * BAR).view()).unwrap();
+    /// assert_relative_eq!(p, arr2(&[[1.0, 2.0], [3.0, 4.0]]) * BAR);
+    /// ```
+    pub fn push_row(&mut self, row: Quantity<ArrayView1<'_, T>, U>) -> Result<(), ShapeError>
+    where
+        T: Clone,
+    {
+        self.0.push_row(row.0)
+    }
+
+    /// Append a column to a two-dimensional array.
+    ///
+    /// Errors if the length of the column does not match the number of rows.
+    pub fn push_column(&mut self, column: Quantity<ArrayView1<'_, T>, U>) -> Result<(), ShapeError>
+    where
+        T: Clone,
+    {
+        self.0.push_column(column.0)
+    }
+}
+
 impl<T, S: Data<Elem = T>, U, D: Dimension> Quantity<ArrayBase<S, D>, U> {
     /// Return the total number of elements in the array.
     pub fn len(&self) -> usize {
@@ -101,6 +568,36 @@ impl<T, S: Data<Elem = T>, U, D: Dimension> Quantity<ArrayBase<S, D>, U> {
         Quantity::new(self.0.sum())
     }
 
+    /// Return the dot product of two arrays.
+    ///
+    /// For one- and two-dimensional arrays, this is the inner product of vectors, the
+    /// matrix-vector product or the matrix product, depending on the dimensions of `self`
+    /// and `rhs` (see [Dot](ndarray::linalg::Dot)).
+    ///
+    /// **Panics** if the shapes of the arrays are not compatible.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{BAR, METER, JOULE};
+    /// # use ndarray::{arr1, arr2};
+    /// # use approx::assert_relative_eq;
+    /// let p = arr2(&[[1.0, 2.0], [3.0, 4.0]]) * BAR;
+    /// let v = arr1(&[1.0, 2.0]) * METER.powi::<3>();
+    /// assert_relative_eq!(p.dot(&v), arr1(&[5e5, 1.1e6]) * JOULE);
+    /// assert_relative_eq!(v.dot(&v), 5.0 * METER.powi::<6>());
+    /// ```
+    #[expect(clippy::type_complexity)]
+    pub fn dot<S2: Data<Elem = T>, D2: Dimension, U2>(
+        &self,
+        rhs: &Quantity<ArrayBase<S2, D2>, U2>,
+    ) -> Quantity<<ArrayBase<S, D> as Dot<ArrayBase<S2, D2>>>::Output, Sum<U, U2>>
+    where
+        ArrayBase<S, D>: Dot<ArrayBase<S2, D2>>,
+        U: Add<U2>,
+    {
+        Quantity::new(self.0.dot(&rhs.0))
+    }
+
     /// Return an uniquely owned copy of the array.
     pub fn to_owned(&self) -> Quantity<Array<T, D>, U>
     where
@@ -137,6 +634,156 @@ impl<T, S: Data<Elem = T>, U, D: Dimension> Quantity<ArrayBase<S, D>, U> {
         Quantity::new(self.0.index_axis(axis, index))
     }
 
+    /// Return a read-only view of the array.
+    pub fn view(&self) -> Quantity<ArrayView<'_, T, D>, U> {
+        Quantity::new(self.0.view())
+    }
+
+    /// Return a read-write view of the array.
+    pub fn view_mut(&mut self) -> Quantity<ArrayViewMut<'_, T, D>, U>
+    where
+        S: DataMut,
+    {
+        Quantity::new(self.0.view_mut())
+    }
+
+    /// Return a view of the array broadcast to `shape`, or `None` if the shapes are not
+    /// compatible.
+    ///
+    /// Arithmetic operations between references of quantity arrays with different
+    /// dimensionality broadcast both operands automatically (following the rules of
+    /// [ndarray]), while the units are combined and checked as usual.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Density, Temperature, KELVIN, MOL, METER};
+    /// # use ndarray::{arr1, arr2, Array1, Array2};
+    /// # use approx::assert_relative_eq;
+    /// let t: Temperature<Array1<f64>> = arr1(&[300.0, 400.0, 500.0]) * KELVIN;
+    /// assert_relative_eq!(
+    ///     t.broadcast((2, 3)).unwrap().to_owned(),
+    ///     arr2(&[[300.0, 400.0, 500.0], [300.0, 400.0, 500.0]]) * KELVIN
+    /// );
+    ///
+    /// let rho: Density<Array2<f64>> = arr2(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]) * MOL / METER.powi::<3>();
+    /// let prod = &rho * &t;
+    /// assert_relative_eq!(
+    ///     prod,
+    ///     arr2(&[[300.0, 800.0, 1500.0], [1200.0, 2000.0, 3000.0]]) * MOL / METER.powi::<3>() * KELVIN
+    /// );
+    /// ```
+    pub fn broadcast<E: IntoDimension>(
+        &self,
+        shape: E,
+    ) -> Option<Quantity<ArrayView<'_, T, E::Dim>, U>> {
+        self.0.broadcast(shape).map(Quantity::new)
+    }
+
+    /// Return a sliced view of the array.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Length, METER};
+    /// # use ndarray::{arr1, s};
+    /// # use approx::assert_relative_eq;
+    /// let x = Length::linspace(1.0 * METER, 3.0 * METER, 5);
+    /// assert_relative_eq!(x.slice(s![1..;2]).to_owned(), &(arr1(&[1.5, 2.5]) * METER));
+    /// ```
+    pub fn slice<I: SliceArg<D>>(&self, info: I) -> Quantity<ArrayView<'_, T, I::OutDim>, U> {
+        Quantity::new(self.0.slice(info))
+    }
+
+    /// Return a sliced read-write view of the array.
+    pub fn slice_mut<I: SliceArg<D>>(
+        &mut self,
+        info: I,
+    ) -> Quantity<ArrayViewMut<'_, T, I::OutDim>, U>
+    where
+        S: DataMut,
+    {
+        Quantity::new(self.0.slice_mut(info))
+    }
+
+    /// Slice the array, possibly changing the number of dimensions.
+    pub fn slice_move<I: SliceArg<D>>(self, info: I) -> Quantity<ArrayBase<S, I::OutDim>, U> {
+        Quantity::new(self.0.slice_move(info))
+    }
+
+    /// Return an iterator over views of the array along `axis`, with the axis removed.
+    pub fn axis_iter(
+        &self,
+        axis: Axis,
+    ) -> impl ExactSizeIterator<Item = Quantity<ArrayView<'_, T, D::Smaller>, U>> + DoubleEndedIterator
+    where
+        D: RemoveAxis,
+    {
+        self.0.axis_iter(axis).map(Quantity::new)
+    }
+
+    /// Return an iterator over views of the array along the first axis, with the axis removed.
+    pub fn outer_iter(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Quantity<ArrayView<'_, T, D::Smaller>, U>> + DoubleEndedIterator
+    where
+        D: RemoveAxis,
+    {
+        self.0.outer_iter().map(Quantity::new)
+    }
+
+    /// Return an iterator over the rows (the 1D lanes along the last axis) of the array.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::KELVIN;
+    /// # use ndarray::{arr1, arr2};
+    /// # use approx::assert_relative_eq;
+    /// let t = arr2(&[[300.0, 310.0], [320.0, 330.0]]) * KELVIN;
+    /// let sums: Vec<_> = t.rows().map(|row| row.sum()).collect();
+    /// assert_relative_eq!(sums[1], 650.0 * KELVIN);
+    /// ```
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = Quantity<ArrayView1<'_, T>, U>> {
+        self.0.rows().into_iter().map(Quantity::new)
+    }
+
+    /// Return an iterator over the columns (the 1D lanes along the first axis) of the array.
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = Quantity<ArrayView1<'_, T>, U>> {
+        self.0.columns().into_iter().map(Quantity::new)
+    }
+
+    /// Create a new array by selecting the `indices` along `axis`.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::BAR;
+    /// # use ndarray::{arr1, Axis};
+    /// # use approx::assert_relative_eq;
+    /// let p = arr1(&[1.0, 2.0, 3.0, 4.0]) * BAR;
+    /// assert_relative_eq!(p.select(Axis(0), &[3, 0]), arr1(&[4.0, 1.0]) * BAR);
+    /// ```
+    pub fn select(&self, axis: Axis, indices: &[usize]) -> Quantity<Array<T, D>, U>
+    where
+        T: Clone,
+        D: RemoveAxis,
+    {
+        Quantity::new(self.0.select(axis, indices))
+    }
+
+    /// Split the array into two views at `index` along `axis`.
+    ///
+    /// **Panics** if `axis` or `index` is out of bounds.
+    #[expect(clippy::type_complexity)]
+    pub fn split_at(
+        &self,
+        axis: Axis,
+        index: usize,
+    ) -> (
+        Quantity<ArrayView<'_, T, D>, U>,
+        Quantity<ArrayView<'_, T, D>, U>,
+    ) {
+        let (a, b) = self.0.view().split_at(axis, index);
+        (Quantity::new(a), Quantity::new(b))
+    }
+
     /// Return a producer and iterable that traverses over all 1D lanes pointing in the direction of axis.
     pub fn lanes_mut(&mut self, axis: Axis) -> LanesMut<'_, T, D::Smaller>
     where
@@ -154,6 +801,206 @@ impl<T, S: Data<Elem = T>, U, D: Dimension> Quantity<ArrayBase<S, D>, U> {
         Quantity::new(self.0.sum_axis(axis))
     }
 
+    /// Return the arithmetic mean of all elements in the array or `None` if the array is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::KELVIN;
+    /// # use ndarray::arr1;
+    /// # use approx::assert_relative_eq;
+    /// let t = arr1(&[300.0, 310.0, 350.0]) * KELVIN;
+    /// assert_relative_eq!(t.mean().unwrap(), 320.0 * KELVIN);
+    /// ```
+    pub fn mean(&self) -> Option<Quantity<T, U>>
+    where
+        T: Clone + FromPrimitive + Add<Output = T> + Div<Output = T> + Zero,
+    {
+        self.0.mean().map(Quantity::new)
+    }
+
+    /// Return the arithmetic mean along axis or `None` if the length of the axis is zero.
+    pub fn mean_axis(&self, axis: Axis) -> Option<Quantity<Array<T, D::Smaller>, U>>
+    where
+        T: Clone + FromPrimitive + Add<Output = T> + Div<Output = T> + Zero,
+        D: RemoveAxis,
+    {
+        self.0.mean_axis(axis).map(Quantity::new)
+    }
+
+    /// Return the variance of all elements in the array.
+    ///
+    /// The variance is calculated with `n - ddof` as denominator, where `n` is the number of elements.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than `n`.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{KELVIN, METER};
+    /// # use ndarray::arr1;
+    /// # use approx::assert_relative_eq;
+    /// let x = arr1(&[1.0, 2.0, 3.0, 4.0]) * METER;
+    /// assert_relative_eq!(x.var(1.0), 5.0 / 3.0 * METER * METER);
+    /// assert_relative_eq!(x.std(0.0), 1.25f64.sqrt() * METER);
+    /// ```
+    pub fn var(&self, ddof: T) -> Quantity<T, Sum<U, U>>
+    where
+        T: Float + FromPrimitive,
+        U: Add<U>,
+    {
+        Quantity::new(self.0.var(ddof))
+    }
+
+    /// Return the standard deviation of all elements in the array.
+    ///
+    /// The variance is calculated with `n - ddof` as denominator, where `n` is the number of elements.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than `n`.
+    pub fn std(&self, ddof: T) -> Quantity<T, U>
+    where
+        T: Float + FromPrimitive,
+    {
+        Quantity::new(self.0.std(ddof))
+    }
+
+    /// Return the variance along axis.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than the length of the axis.
+    pub fn var_axis(&self, axis: Axis, ddof: T) -> Quantity<Array<T, D::Smaller>, Sum<U, U>>
+    where
+        T: Float + FromPrimitive,
+        U: Add<U>,
+        D: RemoveAxis,
+    {
+        Quantity::new(self.0.var_axis(axis, ddof))
+    }
+
+    /// Return the standard deviation along axis.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than the length of the axis.
+    pub fn std_axis(&self, axis: Axis, ddof: T) -> Quantity<Array<T, D::Smaller>, U>
+    where
+        T: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        Quantity::new(self.0.std_axis(axis, ddof))
+    }
+
+    /// Return the minimum of all elements in the array.
+    ///
+    /// NaN values are ignored. Returns `None` if the array contains no values other than NaN.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::BAR;
+    /// # use ndarray::arr1;
+    /// # use approx::assert_relative_eq;
+    /// let p = arr1(&[3.0, f64::NAN, 1.0, 2.0]) * BAR;
+    /// assert_relative_eq!(p.min().unwrap(), BAR);
+    /// assert_eq!(p.argmin(), Some(2));
+    /// ```
+    pub fn min(&self) -> Option<Quantity<T, U>>
+    where
+        T: Clone + PartialOrd,
+    {
+        self.extremum(|x, y| x < y)
+            .map(|(_, x)| Quantity::new(x.clone()))
+    }
+
+    /// Return the maximum of all elements in the array.
+    ///
+    /// NaN values are ignored. Returns `None` if the array contains no values other than NaN.
+    pub fn max(&self) -> Option<Quantity<T, U>>
+    where
+        T: Clone + PartialOrd,
+    {
+        self.extremum(|x, y| x > y)
+            .map(|(_, x)| Quantity::new(x.clone()))
+    }
+
+    /// Return the index of the minimum of all elements in the array.
+    ///
+    /// NaN values are ignored. Returns `None` if the array contains no values other than NaN.
+    pub fn argmin(&self) -> Option<D::Pattern>
+    where
+        T: PartialOrd,
+    {
+        self.extremum(|x, y| x < y).map(|(i, _)| i)
+    }
+
+    /// Return the index of the maximum of all elements in the array.
+    ///
+    /// NaN values are ignored. Returns `None` if the array contains no values other than NaN.
+    pub fn argmax(&self) -> Option<D::Pattern>
+    where
+        T: PartialOrd,
+    {
+        self.extremum(|x, y| x > y).map(|(i, _)| i)
+    }
+
+    fn extremum<F: Fn(&T, &T) -> bool>(&self, replace: F) -> Option<(D::Pattern, &T)>
+    where
+        T: PartialOrd,
+    {
+        self.0
+            .indexed_iter()
+            .filter(|(_, x)| x.partial_cmp(x).is_some())
+            .fold(None, |acc, (i, x)| match acc {
+                Some((_, y)) if !replace(x, y) => acc,
+                _ => Some((i, x)),
+            })
+    }
+
+    /// Return the product of all elements in the array.
+    ///
+    /// Because the unit of the result depends on the number of elements, it has to be
+    /// specified at compile time.
+    ///
+    /// **Panics** if `N` is negative or if the number of elements is not equal to `N`.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{METER, Volume};
+    /// # use ndarray::arr1;
+    /// # use approx::assert_relative_eq;
+    /// let edges = arr1(&[2.0, 3.0, 4.0]) * METER;
+    /// let volume: Volume = edges.product::<3>();
+    /// assert_relative_eq!(volume, 24.0 * METER.powi::<3>());
+    /// ```
+    pub fn product<const N: i8>(&self) -> Quantity<T, Prod<U, Const<N>>>
+    where
+        T: Clone + One + Mul<Output = T>,
+        U: Mul<Const<N>>,
+    {
+        assert!(N >= 0, "The exponent of the unit must not be negative.");
+        assert_eq!(
+            self.len(),
+            N as usize,
+            "The number of elements has to match the exponent of the unit."
+        );
+        Quantity::new(self.0.product())
+    }
+
+    /// Fold along an axis.
+    ///
+    /// Combine the elements of each subview with the previous using the fold function
+    /// and initial value init.
+    pub fn fold_axis<T2, U2, F>(
+        &self,
+        axis: Axis,
+        init: Quantity<T2, U2>,
+        mut fold: F,
+    ) -> Quantity<Array<T2, D::Smaller>, U2>
+    where
+        T: Clone,
+        T2: Clone,
+        D: RemoveAxis,
+        F: FnMut(Quantity<T2, U2>, Quantity<T, U>) -> Quantity<T2, U2>,
+    {
+        Quantity::new(self.0.fold_axis(axis, init.0, |acc, x| {
+            fold(Quantity::new(acc.clone()), Quantity::new(x.clone())).0
+        }))
+    }
+
     /// Insert new array axis at axis and return the result.
     pub fn insert_axis(self, axis: Axis) -> Quantity<ArrayBase<S, D::Larger>, U> {
         Quantity::new(self.0.insert_axis(axis))
@@ -192,53 +1039,360 @@ impl<T, S: Data<Elem = T>, U, D: Dimension> Quantity<ArrayBase<S, D>, U> {
     {
         self.0[index] = value.0;
     }
-}
 
-pub struct QuantityIter<I, U> {
-    inner: I,
-    unit: PhantomData<U>,
-}
+    /// Return an iterator of mutable references to the elements of the array.
+    ///
+    /// Elements are visited in logical order.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Temperature, KELVIN};
+    /// # use ndarray::{arr1, Array1};
+    /// # use approx::assert_relative_eq;
+    /// let mut t: Temperature<Array1<f64>> = arr1(&[300.0, 400.0]) * KELVIN;
+    /// for ti in t.iter_mut() {
+    ///     *ti += 10.0 * KELVIN;
+    /// }
+    /// assert_relative_eq!(t, arr1(&[310.0, 410.0]) * KELVIN);
+    /// ```
+    pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = &mut Quantity<T, U>>
+    where
+        S: DataMut,
+    {
+        self.0.iter_mut().map(Quantity::from_mut)
+    }
 
-impl<'a, I: Iterator<Item = &'a T>, T: Copy + 'static, U: Copy> Iterator for QuantityIter<I, U> {
-    type Item = Quantity<T, U>;
+    /// Return an iterator of indexes and clones of the elements of the array.
+    ///
+    /// Elements are visited in logical order.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Pressure, BAR};
+    /// # use ndarray::{arr2, Array2};
+    /// # use approx::assert_relative_eq;
+    /// let p: Pressure<Array2<f64>> = arr2(&[[1.0, 2.0], [3.0, 4.0]]) * BAR;
+    /// let (index, value) = p.indexed_iter().last().unwrap();
+    /// assert_eq!(index, (1, 1));
+    /// assert_relative_eq!(value, 4.0 * BAR);
+    /// ```
+    pub fn indexed_iter(&self) -> impl ExactSizeIterator<Item = (D::Pattern, Quantity<T, U>)>
+    where
+        T: Clone,
+    {
+        self.0
+            .indexed_iter()
+            .map(|(i, x)| (i, Quantity::new(x.clone())))
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.inner.next().map(|value| Quantity::new(*value))
+    /// Return an iterator of indexes and mutable references to the elements of the array.
+    ///
+    /// Elements are visited in logical order.
+    pub fn indexed_iter_mut(
+        &mut self,
+    ) -> impl ExactSizeIterator<Item = (D::Pattern, &mut Quantity<T, U>)>
+    where
+        S: DataMut,
+    {
+        self.0
+            .indexed_iter_mut()
+            .map(|(i, x)| (i, Quantity::from_mut(x)))
     }
+}
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.inner.size_hint()
+/// Right-hand side of elementwise operations on quantity arrays.
+///
+/// Implemented for scalar quantities and references to quantity arrays, which are
+/// broadcast to the shape of the left-hand side.
+pub trait ArrayOperand<T, U, D: Dimension> {
+    /// Return a view of the values in SI units with shape `dim`, or `None` if the shapes
+    /// are not compatible.
+    fn broadcast_to(&self, dim: D) -> Option<ArrayView<'_, T, D>>;
+}
+
+impl<T, U, D: Dimension> ArrayOperand<T, U, D> for Quantity<T, U> {
+    fn broadcast_to(&self, dim: D) -> Option<ArrayView<'_, T, D>> {
+        let strides = D::zeros(dim.ndim());
+        ArrayView::from_shape(dim.strides(strides), std::slice::from_ref(&self.0)).ok()
     }
 }
 
-impl<'a, I: Iterator<Item = &'a T> + ExactSizeIterator, T: Copy + 'static, U: Copy>
-    ExactSizeIterator for QuantityIter<I, U>
+impl<T, S: Data<Elem = T>, U, D: Dimension, E: Dimension> ArrayOperand<T, U, D>
+    for &Quantity<ArrayBase<S, E>, U>
 {
-    fn len(&self) -> usize {
-        self.inner.len()
+    fn broadcast_to(&self, dim: D) -> Option<ArrayView<'_, T, D>> {
+        self.0.broadcast(dim)
     }
 }
 
-impl<'a, I: Iterator<Item = &'a T> + DoubleEndedIterator, T: Copy + 'static, U: Copy>
-    DoubleEndedIterator for QuantityIter<I, U>
-{
-    fn next_back(&mut self) -> Option<Self::Item> {
-        self.inner.next_back().map(|value| Quantity::new(*value))
+impl<T, S: Data<Elem = T>, U, D: Dimension> Quantity<ArrayBase<S, D>, U> {
+    fn compare<C: ArrayOperand<T, U, D>, F: Fn(&T, &T) -> bool>(
+        &self,
+        other: C,
+        f: F,
+    ) -> Array<bool, D> {
+        let other = other
+            .broadcast_to(self.0.raw_dim())
+            .expect("The shapes of the arrays are not compatible.");
+        Zip::from(&self.0).and(&other).map_collect(f)
+    }
+
+    /// Elementwise `self < other` for a scalar quantity or a quantity array with the same unit.
+    ///
+    /// Arrays are broadcast to the shape of `self`.
+    ///
+    /// **Panics** if the shapes are not compatible.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Temperature, KELVIN};
+    /// # use ndarray::{arr1, Array1};
+    /// let t: Temperature<Array1<f64>> = arr1(&[250.0, 300.0, 350.0]) * KELVIN;
+    /// assert_eq!(t.lt(300.0 * KELVIN), arr1(&[true, false, false]));
+    /// assert_eq!(t.ge(&(arr1(&[200.0, 400.0, 350.0]) * KELVIN)), arr1(&[true, false, true]));
+    /// ```
+    pub fn lt<C: ArrayOperand<T, U, D>>(&self, other: C) -> Array<bool, D>
+    where
+        T: PartialOrd,
+    {
+        self.compare(other, |x, y| x < y)
+    }
+
+    /// Elementwise `self <= other` for a scalar quantity or a quantity array with the same unit.
+    ///
+    /// **Panics** if the shapes are not compatible.
+    pub fn le<C: ArrayOperand<T, U, D>>(&self, other: C) -> Array<bool, D>
+    where
+        T: PartialOrd,
+    {
+        self.compare(other, |x, y| x <= y)
+    }
+
+    /// Elementwise `self > other` for a scalar quantity or a quantity array with the same unit.
+    ///
+    /// **Panics** if the shapes are not compatible.
+    pub fn gt<C: ArrayOperand<T, U, D>>(&self, other: C) -> Array<bool, D>
+    where
+        T: PartialOrd,
+    {
+        self.compare(other, |x, y| x > y)
+    }
+
+    /// Elementwise `self >= other` for a scalar quantity or a quantity array with the same unit.
+    ///
+    /// **Panics** if the shapes are not compatible.
+    pub fn ge<C: ArrayOperand<T, U, D>>(&self, other: C) -> Array<bool, D>
+    where
+        T: PartialOrd,
+    {
+        self.compare(other, |x, y| x >= y)
+    }
+
+    /// Return the elements for which `mask` is `true` in logical order.
+    ///
+    /// **Panics** if the shapes of the array and the mask are not equal.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Temperature, KELVIN};
+    /// # use ndarray::{arr1, Array1};
+    /// # use approx::assert_relative_eq;
+    /// let t: Temperature<Array1<f64>> = arr1(&[250.0, 300.0, 350.0, 400.0]) * KELVIN;
+    /// let hot = t.mask(&t.gt(300.0 * KELVIN));
+    /// assert_relative_eq!(hot, arr1(&[350.0, 400.0]) * KELVIN);
+    /// ```
+    pub fn mask<S2: Data<Elem = bool>>(&self, mask: &ArrayBase<S2, D>) -> Quantity<Array1<T>, U>
+    where
+        T: Clone,
+    {
+        assert_eq!(
+            self.0.shape(),
+            mask.shape(),
+            "The shapes of the array and the mask have to be equal."
+        );
+        Quantity::new(
+            Zip::from(&self.0)
+                .and(mask)
+                .fold(Vec::new(), |mut acc, x, &m| {
+                    if m {
+                        acc.push(x.clone());
+                    }
+                    acc
+                })
+                .into(),
+        )
+    }
+
+    /// Return an array with the elements of `self` where `mask` is `true` and the elements
+    /// of `other` (a scalar quantity or a quantity array with the same unit) otherwise.
+    ///
+    /// **Panics** if the shapes are not compatible.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Pressure, BAR};
+    /// # use ndarray::{arr1, Array1};
+    /// # use approx::assert_relative_eq;
+    /// let p: Pressure<Array1<f64>> = arr1(&[-1.0, 2.0, -3.0]) * BAR;
+    /// let p = p.select_where(&p.ge(0.0 * BAR), 0.0 * BAR);
+    /// assert_relative_eq!(p, arr1(&[0.0, 2.0, 0.0]) * BAR);
+    /// ```
+    pub fn select_where<S2: Data<Elem = bool>, C: ArrayOperand<T, U, D>>(
+        &self,
+        mask: &ArrayBase<S2, D>,
+        other: C,
+    ) -> Quantity<Array<T, D>, U>
+    where
+        T: Clone,
+    {
+        let other = other
+            .broadcast_to(self.0.raw_dim())
+            .expect("The shapes of the arrays are not compatible.");
+        Quantity::new(
+            Zip::from(&self.0)
+                .and(mask)
+                .and(&other)
+                .map_collect(|x, &m, y| if m { x.clone() } else { y.clone() }),
+        )
+    }
+
+    /// Limit the elements of the array to the interval `[min, max]`.
+    ///
+    /// NaN values are preserved.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Temperature, KELVIN};
+    /// # use ndarray::{arr1, Array1};
+    /// # use approx::assert_relative_eq;
+    /// let t: Temperature<Array1<f64>> = arr1(&[250.0, 300.0, 350.0]) * KELVIN;
+    /// let t = t.clip(280.0 * KELVIN, 320.0 * KELVIN);
+    /// assert_relative_eq!(t, arr1(&[280.0, 300.0, 320.0]) * KELVIN);
+    /// ```
+    pub fn clip(&self, min: Quantity<T, U>, max: Quantity<T, U>) -> Quantity<Array<T, D>, U>
+    where
+        T: Clone + PartialOrd,
+    {
+        Quantity::new(self.0.map(|x| {
+            if *x < min.0 {
+                min.0.clone()
+            } else if *x > max.0 {
+                max.0.clone()
+            } else {
+                x.clone()
+            }
+        }))
     }
 }
 
-impl<'a, F, T: Copy + 'static, U: Copy> IntoIterator for &'a Quantity<F, U>
-where
-    &'a F: IntoIterator<Item = &'a T>,
-{
-    type Item = Quantity<T, U>;
-    type IntoIter = QuantityIter<<&'a F as IntoIterator>::IntoIter, U>;
+impl<T, S: Data<Elem = T>, U> Quantity<ArrayBase<S, Ix1>, U> {
+    /// Return a sorted copy of the array.
+    ///
+    /// NaN values are placed at the end.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Length, METER};
+    /// # use ndarray::{arr1, Array1};
+    /// # use approx::assert_relative_eq;
+    /// let x: Length<Array1<f64>> = arr1(&[3.0, 1.0, 2.0]) * METER;
+    /// assert_relative_eq!(x.sort(), arr1(&[1.0, 2.0, 3.0]) * METER);
+    /// assert_eq!(x.argsort(), vec![1, 2, 0]);
+    /// ```
+    pub fn sort(&self) -> Quantity<Array1<T>, U>
+    where
+        T: Clone + PartialOrd,
+    {
+        Quantity::new(
+            self.argsort()
+                .into_iter()
+                .map(|i| self.0[i].clone())
+                .collect(),
+        )
+    }
+
+    /// Return the indices that sort the array.
+    ///
+    /// The sort is stable and NaN values are placed at the end.
+    pub fn argsort(&self) -> Vec<usize>
+    where
+        T: PartialOrd,
+    {
+        let mut indices: Vec<_> = (0..self.0.len()).collect();
+        indices.sort_by(|&i, &j| {
+            let (x, y) = (&self.0[i], &self.0[j]);
+            x.partial_cmp(y).unwrap_or_else(|| {
+                // at least one of the values is NaN, which are placed at the end
+                let x_nan = x.partial_cmp(x).is_none();
+                let y_nan = y.partial_cmp(y).is_none();
+                x_nan.cmp(&y_nan)
+            })
+        });
+        indices
+    }
+
+    /// Return the coordinate matrices of the grid spanned by `self` and `other`.
+    ///
+    /// The grids use matrix indexing, i.e., the element `[i, j]` of the two matrices
+    /// corresponds to the `i`-th element of `self` and the `j`-th element of `other`.
+    ///
+    /// # Example
+    /// Calculate the pressure of an ideal gas for every combination of temperature and
+    /// density.
+    /// ```
+    /// # use quantity::{Density, Pressure, Temperature, KELVIN, MOL, METER, RGAS, BAR};
+    /// # use ndarray::{arr1, arr2, Array1, Array2};
+    /// # use approx::assert_relative_eq;
+    /// let temperature: Temperature<Array1<f64>> = arr1(&[300.0, 400.0]) * KELVIN;
+    /// let density = arr1(&[10.0, 20.0, 30.0]) * MOL / METER.powi::<3>();
+    /// let (t, rho) = temperature.meshgrid(&density);
+    /// let p: Pressure<Array2<f64>> = &rho * RGAS * &t;
+    /// assert_eq!(p.shape(), &[2, 3]);
+    /// assert_relative_eq!(p.get((1, 2)), 30.0 * MOL / METER.powi::<3>() * RGAS * 400.0 * KELVIN);
+    /// ```
+    pub fn meshgrid<S2: Data<Elem = T>, U2>(
+        &self,
+        other: &Quantity<ArrayBase<S2, Ix1>, U2>,
+    ) -> (Quantity<Array2<T>, U>, Quantity<Array2<T>, U2>)
+    where
+        T: Clone,
+    {
+        let shape = (self.0.len(), other.0.len());
+        (
+            Quantity::new(Array2::from_shape_fn(shape, |(i, _)| self.0[i].clone())),
+            Quantity::new(Array2::from_shape_fn(shape, |(_, j)| other.0[j].clone())),
+        )
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        QuantityIter {
-            inner: self.0.into_iter(),
-            unit: PhantomData,
+    /// Return the index at which `value` would have to be inserted to keep the
+    /// (ascendingly sorted) array sorted.
+    ///
+    /// If `value` is equal to elements of the array, the index of the first of those
+    /// is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Length, METER};
+    /// # use ndarray::{arr1, Array1};
+    /// let x: Length<Array1<f64>> = arr1(&[1.0, 2.0, 2.0, 3.0]) * METER;
+    /// assert_eq!(x.searchsorted(2.0 * METER), 1);
+    /// assert_eq!(x.searchsorted(2.5 * METER), 3);
+    /// assert_eq!(x.searchsorted(5.0 * METER), 4);
+    /// ```
+    pub fn searchsorted(&self, value: Quantity<T, U>) -> usize
+    where
+        T: PartialOrd,
+    {
+        let (mut lo, mut hi) = (0, self.0.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.0[mid] < value.0 {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
         }
+        lo
     }
 }
 
@@ -250,3 +1404,75 @@ impl<T, U> FromIterator<Quantity<T, U>> for Quantity<Array1<T>, U> {
         Self::new(iter.into_iter().map(|v| v.0).collect())
     }
 }
+
+impl<T: Clone, U> Extend<Quantity<T, U>> for Quantity<Array1<T>, U> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Quantity<T, U>>,
+    {
+        let values: Array1<T> = iter.into_iter().map(|v| v.0).collect();
+        self.0.append(Axis(0), values.view()).unwrap();
+    }
+}
+
+/// Reinterpret a view of quantities as a quantity of a view without copying.
+///
+/// Views of buffers in SI units, e.g. from memory-mapped files, can be created
+/// directly as quantities using [Quantity::new] without copying the data.
+///
+/// # Example
+/// ```
+/// # use quantity::{Length, METER};
+/// # use ndarray::{ArrayView, ArrayView1};
+/// # use approx::assert_relative_eq;
+/// let buffer = [1.0, 2.0, 3.0, 4.0];
+/// let x: Length<ArrayView1<f64>> = Length::new(ArrayView::from(&buffer));
+/// let y: ArrayView1<Length> = x.into();
+/// assert_relative_eq!(y[1], 2.0 * METER);
+/// let z: Length<ArrayView1<f64>> = y.into();
+/// assert_relative_eq!(z.get(3), 4.0 * METER);
+/// ```
+impl<'a, T, U, D: Dimension> From<ArrayView<'a, Quantity<T, U>, D>>
+    for Quantity<ArrayView<'a, T, D>, U>
+{
+    fn from(view: ArrayView<'a, Quantity<T, U>, D>) -> Self {
+        // SAFETY: `Quantity` is `repr(transparent)` over `T` and the lifetime of the
+        // returned view is the same as the lifetime of `view`.
+        Quantity::new(unsafe { view.raw_view().cast::<T>().deref_into_view() })
+    }
+}
+
+/// Reinterpret a quantity of a view as a view of quantities without copying.
+impl<'a, T, U, D: Dimension> From<Quantity<ArrayView<'a, T, D>, U>>
+    for ArrayView<'a, Quantity<T, U>, D>
+{
+    fn from(view: Quantity<ArrayView<'a, T, D>, U>) -> Self {
+        // SAFETY: see above
+        unsafe { view.0.raw_view().cast::<Quantity<T, U>>().deref_into_view() }
+    }
+}
+
+/// Reinterpret a mutable view of quantities as a quantity of a mutable view without copying.
+impl<'a, T, U, D: Dimension> From<ArrayViewMut<'a, Quantity<T, U>, D>>
+    for Quantity<ArrayViewMut<'a, T, D>, U>
+{
+    fn from(mut view: ArrayViewMut<'a, Quantity<T, U>, D>) -> Self {
+        // SAFETY: see above; `view` is consumed, so the returned view has unique access
+        Quantity::new(unsafe { view.raw_view_mut().cast::<T>().deref_into_view_mut() })
+    }
+}
+
+/// Reinterpret a quantity of a mutable view as a mutable view of quantities without copying.
+impl<'a, T, U, D: Dimension> From<Quantity<ArrayViewMut<'a, T, D>, U>>
+    for ArrayViewMut<'a, Quantity<T, U>, D>
+{
+    fn from(mut view: Quantity<ArrayViewMut<'a, T, D>, U>) -> Self {
+        // SAFETY: see above
+        unsafe {
+            view.0
+                .raw_view_mut()
+                .cast::<Quantity<T, U>>()
+                .deref_into_view_mut()
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use super::*;
 #[cfg(feature = "ndarray")]
-use ndarray::{Array, Dimension};
+use ndarray::{Array, ArrayBase, ArrayViewD, Data, Dimension};
 use std::collections::HashMap;
 use std::fmt;
 use std::sync::LazyLock;
@@ -47,8 +47,8 @@ pub(crate) trait PrintUnit {
 }
 
 macro_rules! impl_fmt {
-    ($t:expr, $l:expr, $m:expr, $i:expr, $theta:expr, $n:expr, $unit:expr, $symbol:expr, $has_prefix:expr) => {
-        impl<T> fmt::LowerExp for Quantity<T, SIUnit<$t, $l, $m, $i, $theta, $n, 0>>
+    ($t:expr, $l:expr, $m:expr, $i:expr, $theta:expr, $n:expr, $j:expr, $unit:expr, $symbol:expr, $has_prefix:expr) => {
+        impl<T> fmt::LowerExp for Quantity<T, SIUnit<$t, $l, $m, $i, $theta, $n, $j>>
         where
             for<'a> &'a T: Div<f64>,
             for<'a> Quot<&'a T, f64>: fmt::LowerExp,
@@ -59,7 +59,7 @@ macro_rules! impl_fmt {
             }
         }
 
-        impl<T> fmt::UpperExp for Quantity<T, SIUnit<$t, $l, $m, $i, $theta, $n, 0>>
+        impl<T> fmt::UpperExp for Quantity<T, SIUnit<$t, $l, $m, $i, $theta, $n, $j>>
         where
             for<'a> &'a T: Div<f64>,
             for<'a> Quot<&'a T, f64>: fmt::UpperExp,
@@ -72,7 +72,7 @@ macro_rules! impl_fmt {
 
         #[cfg(feature = "ndarray")]
         impl<D: Dimension> fmt::Display
-            for Quantity<Array<f64, D>, SIUnit<$t, $l, $m, $i, $theta, $n, 0>>
+            for Quantity<Array<f64, D>, SIUnit<$t, $l, $m, $i, $theta, $n, $j>>
         {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 (self / $unit).into_value().fmt(f)?;
@@ -80,7 +80,7 @@ macro_rules! impl_fmt {
             }
         }
 
-        impl fmt::Display for Quantity<f64, SIUnit<$t, $l, $m, $i, $theta, $n, 0>> {
+        impl fmt::Display for Quantity<f64, SIUnit<$t, $l, $m, $i, $theta, $n, $j>> {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 let (value, prefix) = get_prefix((self / $unit).into_value(), $has_prefix);
                 if !((1e-2..1e4).contains(&value.abs()) || value == 0.0) {
@@ -92,31 +92,51 @@ macro_rules! impl_fmt {
             }
         }
 
+        impl fmt::Display for FormattedQuantity<'_, f64, SIUnit<$t, $l, $m, $i, $theta, $n, $j>> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let value = (self.quantity / $unit).into_value();
+                self.options.fmt_scalar(f, value, $symbol, $has_prefix)
+            }
+        }
+
+        #[cfg(feature = "ndarray")]
+        impl<S: Data<Elem = f64>, D: Dimension> fmt::Display
+            for FormattedQuantity<'_, ArrayBase<S, D>, SIUnit<$t, $l, $m, $i, $theta, $n, $j>>
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let value = (self.quantity / $unit).into_value();
+                self.options
+                    .fmt_array(f, value.view().into_dyn(), $symbol, $has_prefix)
+            }
+        }
+
         #[cfg(feature = "python")]
-        impl<T> PrintUnit for Quantity<T, SIUnit<$t, $l, $m, $i, $theta, $n, 0>> {
+        impl<T> PrintUnit for Quantity<T, SIUnit<$t, $l, $m, $i, $theta, $n, $j>> {
             const UNIT: &'static str = $symbol;
         }
     };
 }
 
-impl_fmt!(1, 0, 0, 0, 0, 0, SECOND, "s", Some(KILO));
-impl_fmt!(0, 1, 0, 0, 0, 0, METER, "m", Some(MEGA));
-impl_fmt!(0, 0, 1, 0, 0, 0, GRAM, "g", Some(MEGA));
-impl_fmt!(0, 0, 0, 0, 0, 1, MOL, "mol", Some(MEGA));
-impl_fmt!(0, 0, 0, 0, 1, 0, KELVIN, "K", None);
-impl_fmt!(-1, 0, 0, 0, 0, 0, HERTZ, "Hz", Some(PETA));
-impl_fmt!(-2, 1, 1, 0, 0, 0, NEWTON, "N", Some(PETA));
-impl_fmt!(-2, -1, 1, 0, 0, 0, PASCAL, "Pa", Some(PETA));
-impl_fmt!(-2, 2, 1, 0, 0, 0, JOULE, "J", Some(PETA));
-impl_fmt!(-3, 2, 1, 0, 0, 0, WATT, "W", Some(PETA));
-impl_fmt!(1, 0, 0, 1, 0, 0, COULOMB, "C", None);
-impl_fmt!(-3, 2, 1, -1, 0, 0, VOLT, "V", Some(PETA));
-impl_fmt!(4, -2, -1, 2, 0, 0, FARAD, "F", Some(PETA));
-impl_fmt!(-3, 2, 1, -2, 0, 0, OHM, "Ω", Some(PETA));
-impl_fmt!(3, -2, -1, 2, 0, 0, SIEMENS, "S", Some(PETA));
-impl_fmt!(-2, 2, 1, -1, 0, 0, WEBER, "Wb", Some(PETA));
-impl_fmt!(-2, 0, 1, -1, 0, 0, TESLA, "T", Some(PETA));
-impl_fmt!(-2, 2, 1, -2, 0, 0, HENRY, "H", Some(PETA));
+impl_fmt!(1, 0, 0, 0, 0, 0, 0, SECOND, "s", Some(KILO));
+impl_fmt!(0, 1, 0, 0, 0, 0, 0, METER, "m", Some(MEGA));
+impl_fmt!(0, 0, 1, 0, 0, 0, 0, GRAM, "g", Some(MEGA));
+impl_fmt!(0, 0, 0, 0, 0, 1, 0, MOL, "mol", Some(MEGA));
+impl_fmt!(0, 0, 0, 0, 1, 0, 0, KELVIN, "K", None);
+impl_fmt!(-1, 0, 0, 0, 0, 0, 0, HERTZ, "Hz", Some(PETA));
+impl_fmt!(-2, 1, 1, 0, 0, 0, 0, NEWTON, "N", Some(PETA));
+impl_fmt!(-2, -1, 1, 0, 0, 0, 0, PASCAL, "Pa", Some(PETA));
+impl_fmt!(-2, 2, 1, 0, 0, 0, 0, JOULE, "J", Some(PETA));
+impl_fmt!(-3, 2, 1, 0, 0, 0, 0, WATT, "W", Some(PETA));
+impl_fmt!(1, 0, 0, 1, 0, 0, 0, COULOMB, "C", None);
+impl_fmt!(-3, 2, 1, -1, 0, 0, 0, VOLT, "V", Some(PETA));
+impl_fmt!(4, -2, -1, 2, 0, 0, 0, FARAD, "F", Some(PETA));
+impl_fmt!(-3, 2, 1, -2, 0, 0, 0, OHM, "Ω", Some(PETA));
+impl_fmt!(3, -2, -1, 2, 0, 0, 0, SIEMENS, "S", Some(PETA));
+impl_fmt!(-2, 2, 1, -1, 0, 0, 0, WEBER, "Wb", Some(PETA));
+impl_fmt!(-2, 0, 1, -1, 0, 0, 0, TESLA, "T", Some(PETA));
+impl_fmt!(-2, 2, 1, -2, 0, 0, 0, HENRY, "H", Some(PETA));
+impl_fmt!(0, 0, 0, 0, 0, 0, 1, CANDELA, "cd", Some(MEGA));
+impl_fmt!(0, -2, 0, 0, 0, 0, 1, LUX, "lx", Some(MEGA));
 
 const M2: Area = Quantity::new(1.0);
 const M3: Volume = Quantity::new(1.0);
@@ -126,47 +146,52 @@ const JKGK: SpecificEntropy = Quantity::new(1.0);
 const WMK: ThermalConductivity = Quantity::new(1.0);
 const GS: MassFlowRate = Quantity::new(1e-3);
 
-impl_fmt!(0, -3, 0, 0, 0, 1, MOL / M3, "mol/m³", Some(MEGA));
-impl_fmt!(0, -2, 0, 0, 0, 1, MOL / M2, "mol/m²", Some(MEGA));
-impl_fmt!(0, -1, 0, 0, 0, 1, MOL / METER, "mol/m", Some(MEGA));
-impl_fmt!(0, 3, 0, 0, 0, -1, M3 / MOL, "m³/mol", None);
-impl_fmt!(0, 3, 0, 0, -1, -1, M3 / MOL / KELVIN, "m³/mol/K", None);
-impl_fmt!(0, -3, 1, 0, 0, 0, GRAM / M3, "g/m³", Some(MEGA));
-impl_fmt!(-2, 0, 1, 0, 0, 0, NEWTON / METER, "N/m", Some(PETA));
-impl_fmt!(-1, 2, 1, 0, 0, 0, JOULE * SECOND, "J*s", Some(PETA));
-impl_fmt!(-2, 2, 1, 0, 0, -1, JOULE / MOL, "J/mol", Some(PETA));
-impl_fmt!(-2, 2, 1, 0, -1, 0, JOULE / KELVIN, "J/K", Some(PETA));
-impl_fmt!(-2, 2, 1, 0, -1, -1, JMK, "J/mol/K", Some(PETA));
-impl_fmt!(-2, 2, 0, 0, 0, 0, JOULE / KG, "J/kg", Some(PETA));
-impl_fmt!(-2, 2, 0, 0, -1, 0, JKGK, "J/kg/K", Some(PETA));
-impl_fmt!(-1, -1, 1, 0, 0, 0, PASCAL * SECOND, "Pa*s", Some(PETA));
-impl_fmt!(-1, 1, 0, 0, 0, 0, METER / SECOND, "m/s", Some(MEGA));
-impl_fmt!(-1, 2, 0, 0, 0, 0, M2 / SECOND, "m²/s", None);
-impl_fmt!(-3, 1, 1, 0, -1, 0, WMK, "W/m/K", Some(PETA));
-impl_fmt!(0, 0, 1, 0, 0, -1, GRAM / MOL, "g/mol", Some(MEGA));
-impl_fmt!(0, 2, 0, 0, 0, 0, M2, "m²", None);
-impl_fmt!(0, 3, 0, 0, 0, 0, M3, "m³", None);
-impl_fmt!(-1, 3, -1, 0, 0, 0, M3 / KG / SECOND, "m³/kg/s²", None);
-impl_fmt!(-3, 2, 1, 0, -1, 0, WATT / KELVIN, "W/K", None);
-impl_fmt!(-3, 0, 1, 0, -1, 0, WMK / METER, "W/m²/K", None);
-impl_fmt!(-3, 0, 1, 0, 0, 0, WATT / M2, "W/m²", None);
-impl_fmt!(-1, 0, 1, 0, 0, 0, GS, "g/s", Some(MEGA));
-impl_fmt!(-1, -2, 1, 0, 0, 0, GS / M2, "g/m²/s", Some(MEGA));
+impl_fmt!(-1, 0, 0, 0, 0, 1, 0, MOL / SECOND, "mol/s", Some(MEGA));
+impl_fmt!(0, -3, 0, 0, 0, 1, 0, MOL / M3, "mol/m³", Some(MEGA));
+impl_fmt!(0, -2, 0, 0, 0, 1, 0, MOL / M2, "mol/m²", Some(MEGA));
+impl_fmt!(0, -1, 0, 0, 0, 1, 0, MOL / METER, "mol/m", Some(MEGA));
+impl_fmt!(0, 3, 0, 0, 0, -1, 0, M3 / MOL, "m³/mol", None);
+impl_fmt!(0, 3, 0, 0, -1, -1, 0, M3 / MOL / KELVIN, "m³/mol/K", None);
+impl_fmt!(0, -3, 1, 0, 0, 0, 0, GRAM / M3, "g/m³", Some(MEGA));
+impl_fmt!(-2, 0, 1, 0, 0, 0, 0, NEWTON / METER, "N/m", Some(PETA));
+impl_fmt!(-1, 2, 1, 0, 0, 0, 0, JOULE * SECOND, "J*s", Some(PETA));
+impl_fmt!(-2, 2, 1, 0, 0, -1, 0, JOULE / MOL, "J/mol", Some(PETA));
+impl_fmt!(-2, 2, 1, 0, -1, 0, 0, JOULE / KELVIN, "J/K", Some(PETA));
+impl_fmt!(-2, 2, 1, 0, -1, -1, 0, JMK, "J/mol/K", Some(PETA));
+impl_fmt!(-2, 2, 0, 0, 0, 0, 0, JOULE / KG, "J/kg", Some(PETA));
+impl_fmt!(-2, 2, 0, 0, -1, 0, 0, JKGK, "J/kg/K", Some(PETA));
+impl_fmt!(-1, -1, 1, 0, 0, 0, 0, PASCAL * SECOND, "Pa*s", Some(PETA));
+impl_fmt!(-1, 1, 0, 0, 0, 0, 0, METER / SECOND, "m/s", Some(MEGA));
+impl_fmt!(-1, 2, 0, 0, 0, 0, 0, M2 / SECOND, "m²/s", None);
+impl_fmt!(-3, 1, 1, 0, -1, 0, 0, WMK, "W/m/K", Some(PETA));
+impl_fmt!(0, 0, 1, 0, 0, -1, 0, GRAM / MOL, "g/mol", Some(MEGA));
+impl_fmt!(0, 2, 0, 0, 0, 0, 0, M2, "m²", None);
+impl_fmt!(0, 3, 0, 0, 0, 0, 0, M3, "m³", None);
+impl_fmt!(-1, 3, -1, 0, 0, 0, 0, M3 / KG / SECOND, "m³/kg/s²", None);
+impl_fmt!(-3, 2, 1, 0, -1, 0, 0, WATT / KELVIN, "W/K", None);
+impl_fmt!(-3, 0, 1, 0, -1, 0, 0, WMK / METER, "W/m²/K", None);
+impl_fmt!(-3, 0, 1, 0, 0, 0, 0, WATT / M2, "W/m²", None);
+impl_fmt!(-1, 0, 1, 0, 0, 0, 0, GS, "g/s", Some(MEGA));
+impl_fmt!(-1, -2, 1, 0, 0, 0, 0, GS / M2, "g/m²/s", Some(MEGA));
 
 fn get_prefix(value: f64, has_prefix: Option<f64>) -> (f64, &'static str) {
     if let Some(p) = has_prefix {
-        let abs_value = value.abs();
-        let e: i8 = if abs_value > PICO && abs_value < p {
-            (abs_value.log10().floor() as i8).div_euclid(3) * 3
-        } else {
-            0
-        };
+        let e = prefix_exponent(value, p);
         let prefix = 10.0f64.powi(e as i32);
         return (value / prefix, PREFIX_SYMBOLS.get(&e).unwrap());
     }
     (value, "")
 }
 
+fn prefix_exponent(value: f64, max_prefix: f64) -> i8 {
+    let abs_value = value.abs();
+    if abs_value > PICO && abs_value < max_prefix {
+        (abs_value.log10().floor() as i8).div_euclid(3) * 3
+    } else {
+        0
+    }
+}
+
 static PREFIX_SYMBOLS: LazyLock<HashMap<i8, &'static str>> = LazyLock::new(|| {
     let mut m = HashMap::new();
     m.insert(0, " ");
@@ -178,6 +203,10 @@ static PREFIX_SYMBOLS: LazyLock<HashMap<i8, &'static str>> = LazyLock::new(|| {
     m.insert(-9, "n");
     m.insert(-6, "µ");
     m.insert(-3, "m");
+    m.insert(-2, "c");
+    m.insert(-1, "d");
+    m.insert(1, "da");
+    m.insert(2, "h");
     m.insert(3, "k");
     m.insert(6, "M");
     m.insert(9, "G");
@@ -186,9 +215,343 @@ static PREFIX_SYMBOLS: LazyLock<HashMap<i8, &'static str>> = LazyLock::new(|| {
     m.insert(18, "E");
     m.insert(21, "Z");
     m.insert(24, "Y");
+    m.insert(-30, "q");
+    m.insert(-27, "r");
+    m.insert(27, "R");
+    m.insert(30, "Q");
     m
 });
 
+/// Notation of the numerical value used by [FormatOptions].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Notation {
+    /// Decimal notation for absolute values between $10^{-2}$ and $10^4$ and
+    /// scientific notation otherwise (as in the [Display](fmt::Display) implementation).
+    #[default]
+    Auto,
+    /// Always use decimal notation.
+    Decimal,
+    /// Always use scientific notation.
+    Scientific,
+    /// Scientific notation with exponents that are multiples of three.
+    Engineering,
+}
+
+/// Usage of SI prefixes in [FormatOptions].
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum PrefixPolicy {
+    /// Choose a suitable prefix for units that support prefixes (as in the
+    /// [Display](fmt::Display) implementation).
+    #[default]
+    Auto,
+    /// Never use a prefix.
+    Off,
+    /// Always use the given prefix, e.g., `PrefixPolicy::Forced(KILO)`. The prefix applies
+    /// to the first unit symbol including its power, i.e., $1\\,\text{km}^2=10^6\\,\text{m}^2$.
+    /// Values that are not one of the prefix constants of this crate (e.g., `Forced(2.0)` or
+    /// `Forced(500.0)`) are ignored and the quantity is formatted without prefix.
+    Forced(f64),
+}
+
+/// Options for the formatting of scalar and array quantities.
+///
+/// The options are applied to a quantity via [Quantity::display_with]. The default
+/// options resemble the [Display](fmt::Display) implementation, except that no
+/// additional space is inserted for values without prefix.
+///
+/// # Example
+/// ```
+/// # use quantity::*;
+/// let p = 101325.0 * PASCAL;
+/// let options = FormatOptions::new().significant_digits(4);
+/// assert_eq!(format!("{}", p.display_with(&options)), "101.3 kPa");
+///
+/// let options = options.notation(Notation::Engineering).prefix(PrefixPolicy::Off);
+/// assert_eq!(format!("{}", p.display_with(&options)), "101.3e3 Pa");
+///
+/// let options = FormatOptions::new().ascii(true).separator("");
+/// let d = 1.5 * MICRO * METER;
+/// assert_eq!(format!("{}", d.display_with(&options)), "1.5um");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormatOptions {
+    significant_digits: Option<usize>,
+    precision: Option<usize>,
+    notation: Notation,
+    prefix: PrefixPolicy,
+    ascii: bool,
+    separator: String,
+    align_prefix: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            significant_digits: None,
+            precision: None,
+            notation: Notation::Auto,
+            prefix: PrefixPolicy::Auto,
+            ascii: false,
+            separator: " ".into(),
+            align_prefix: false,
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Create formatting options with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Round values to the given number of significant digits.
+    ///
+    /// Overrides [precision](Self::precision) and the precision of the format string.
+    pub fn significant_digits(mut self, digits: usize) -> Self {
+        self.significant_digits = Some(digits.max(1));
+        self
+    }
+
+    /// Print values with the given number of decimal places.
+    ///
+    /// Overrides the precision of the format string.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Set the notation of the numerical value.
+    pub fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// Set the usage of SI prefixes.
+    pub fn prefix(mut self, prefix: PrefixPolicy) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Only use ASCII characters in unit symbols (e.g., `um` instead of `µm`,
+    /// `m^3` instead of `m³` and `Ohm` instead of `Ω`).
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    /// Set the separator between the numerical value and the unit.
+    pub fn separator<S: Into<String>>(mut self, separator: S) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Insert an additional space for units without prefix, so that values
+    /// with and without prefixes are aligned (as in the [Display](fmt::Display)
+    /// implementation).
+    pub fn align_prefix(mut self, align: bool) -> Self {
+        self.align_prefix = align;
+        self
+    }
+
+    fn exponent(&self, value: f64, has_prefix: Option<f64>) -> Option<i8> {
+        match (self.prefix, has_prefix) {
+            (PrefixPolicy::Auto, Some(p)) => Some(prefix_exponent(value, p)),
+            (PrefixPolicy::Forced(p), _) => {
+                let e = p.log10().round() as i8;
+                let exact = ((10f64.powi(e as i32) - p) / p).abs() < 1e-10;
+                (exact && PREFIX_SYMBOLS.contains_key(&e)).then_some(e)
+            }
+            _ => None,
+        }
+    }
+
+    fn unit(&self, exponent: Option<i8>, symbol: &str) -> String {
+        let prefix = match exponent {
+            Some(0) | None if self.align_prefix && self.prefix == PrefixPolicy::Auto => " ",
+            Some(0) | None => "",
+            Some(e) => PREFIX_SYMBOLS[&e],
+        };
+        let unit = format!("{}{prefix}{symbol}", self.separator);
+        if self.ascii {
+            unit.replace('µ', "u")
+                .replace('Ω', "Ohm")
+                .replace('²', "^2")
+                .replace('³', "^3")
+        } else {
+            unit
+        }
+    }
+
+    fn round(&self, value: f64) -> f64 {
+        match self.significant_digits {
+            Some(n) if value.is_finite() => format!("{value:.0$e}", n - 1).parse().unwrap(),
+            _ => value,
+        }
+    }
+
+    fn decimal(&self, value: f64, precision: Option<usize>) -> String {
+        match (self.significant_digits, precision) {
+            (Some(n), _) => {
+                let magnitude = if value == 0.0 || !value.is_finite() {
+                    0
+                } else {
+                    value.abs().log10().floor() as i32
+                };
+                let decimals = (n as i32 - 1 - magnitude).max(0) as usize;
+                format!("{value:.decimals$}")
+            }
+            (None, Some(p)) => format!("{value:.p$}"),
+            (None, None) => format!("{value}"),
+        }
+    }
+
+    fn resolve_notation(&self, value: f64) -> Notation {
+        match self.notation {
+            Notation::Auto
+                if (1e-2..1e4).contains(&value.abs()) || value == 0.0 || !value.is_finite() =>
+            {
+                Notation::Decimal
+            }
+            Notation::Auto => Notation::Scientific,
+            notation => notation,
+        }
+    }
+
+    fn value(&self, value: f64, notation: Notation, precision: Option<usize>) -> String {
+        let precision = self.precision.or(precision);
+        let value = self.round(value);
+        match notation {
+            Notation::Auto | Notation::Decimal => self.decimal(value, precision),
+            Notation::Scientific => match (self.significant_digits, precision) {
+                (Some(n), _) => format!("{value:.0$e}", n - 1),
+                (None, Some(p)) => format!("{value:.p$e}"),
+                (None, None) => format!("{value:e}"),
+            },
+            Notation::Engineering => {
+                let e = if value == 0.0 || !value.is_finite() {
+                    0
+                } else {
+                    (value.abs().log10().floor() as i32).div_euclid(3) * 3
+                };
+                let mantissa = value / 10.0f64.powi(e);
+                format!("{}e{e}", self.decimal(mantissa, precision))
+            }
+        }
+    }
+
+    fn fmt_scalar(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        value: f64,
+        symbol: &str,
+        has_prefix: Option<f64>,
+    ) -> fmt::Result {
+        let value = self.round(value);
+        let exponent = self.exponent(value, has_prefix);
+        let scale = prefix_scale(exponent, symbol);
+        let value = value / scale;
+        let value = self.value(value, self.resolve_notation(value), f.precision());
+        pad(f, &format!("{value}{}", self.unit(exponent, symbol)))
+    }
+
+    #[cfg(feature = "ndarray")]
+    fn fmt_array(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        value: ArrayViewD<'_, f64>,
+        symbol: &str,
+        has_prefix: Option<f64>,
+    ) -> fmt::Result {
+        let max = value
+            .iter()
+            .filter(|x| x.is_finite())
+            .fold(0.0, |max: f64, x| max.max(x.abs()));
+        let exponent = self.exponent(self.round(max), has_prefix);
+        let scale = prefix_scale(exponent, symbol);
+        let notation = self.resolve_notation(self.round(max) / scale);
+        let mut out = String::new();
+        let value = value.mapv(|x| x / scale);
+        self.write_array(&mut out, value.view(), notation, f.precision());
+        pad(f, &format!("{out}{}", self.unit(exponent, symbol)))
+    }
+
+    #[cfg(feature = "ndarray")]
+    fn write_array(
+        &self,
+        out: &mut String,
+        value: ArrayViewD<'_, f64>,
+        notation: Notation,
+        precision: Option<usize>,
+    ) {
+        match value.first() {
+            Some(&x) if value.ndim() == 0 => out.push_str(&self.value(x, notation, precision)),
+            _ => {
+                out.push('[');
+                for (i, row) in value.outer_iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    self.write_array(out, row, notation, precision);
+                }
+                out.push(']');
+            }
+        }
+    }
+}
+
+/// Factor by which a value is divided when the prefix with the given exponent is applied
+/// to the first unit symbol (including its power) of `symbol`.
+fn prefix_scale(exponent: Option<i8>, symbol: &str) -> f64 {
+    let power = match symbol
+        .split(['/', '*'])
+        .next()
+        .and_then(|s| s.chars().last())
+    {
+        Some('²') => 2,
+        Some('³') => 3,
+        _ => 1,
+    };
+    10.0f64.powi(exponent.unwrap_or(0) as i32 * power)
+}
+
+/// Pad `s` to the width of the format string (right-aligned by default).
+fn pad(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let padding = f.width().unwrap_or(0).saturating_sub(s.chars().count());
+    let (left, right) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (padding, 0),
+    };
+    let fill = f.fill().to_string();
+    write!(f, "{}{s}{}", fill.repeat(left), fill.repeat(right))
+}
+
+/// Wrapper that formats a quantity according to [FormatOptions].
+///
+/// Created by [Quantity::display_with].
+pub struct FormattedQuantity<'a, T, U> {
+    quantity: &'a Quantity<T, U>,
+    options: &'a FormatOptions,
+}
+
+impl<T, U> Quantity<T, U> {
+    /// Return a wrapper that formats `self` according to the given [FormatOptions].
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::*;
+    /// let options = FormatOptions::new().prefix(PrefixPolicy::Forced(KILO)).precision(2);
+    /// assert_eq!(format!("{}", (1500.0 * PASCAL).display_with(&options)), "1.50 kPa");
+    /// assert_eq!(format!("{}", (20.0 * PASCAL).display_with(&options)), "0.02 kPa");
+    /// ```
+    pub fn display_with<'a>(&'a self, options: &'a FormatOptions) -> FormattedQuantity<'a, T, U> {
+        FormattedQuantity {
+            quantity: self,
+            options,
+        }
+    }
+}
+
 impl fmt::Display for Angle {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.to_degrees().fmt(f)?;
@@ -223,6 +586,34 @@ mod tests {
         assert_eq!(format!("{:.3}", m / t / a), "2.500 kg/m²/s");
     }
 
+    #[test]
+    fn test_fmt_photometry() {
+        let options = FormatOptions::new();
+        assert_eq!(
+            format!("{}", (800.0 * LUMEN).display_with(&options)),
+            "800 cd"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                (800.0 * LUMEN / (2.0 * METER * METER)).display_with(&options)
+            ),
+            "400 lx"
+        );
+        assert_eq!(format!("{}", 5.0 * KILO * LUX), "5 klx");
+        assert_eq!(format!("{}", 2.0 * STERADIAN), "2");
+    }
+
+    #[test]
+    fn test_fmt_catalytic_activity() {
+        assert_eq!(format!("{}", 3.0 * MICRO * KATAL), "3 µmol/s");
+        assert_eq!(format!("{}", 2.0 * MILLI * GRAY), "2 mJ/kg");
+        assert_eq!(
+            format!("{}", (50.0 * BECQUEREL).display_with(&FormatOptions::new())),
+            "50 Hz"
+        );
+    }
+
     #[test]
     fn test_fmt_exp() {
         assert_eq!(format!("{:e}", PICO * METER), "1e-12 m");
@@ -254,6 +645,109 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fmt_options() {
+        let options = FormatOptions::new();
+        assert_eq!(
+            format!("{}", RGAS.display_with(&options)),
+            "8.31446261815324 J/mol/K"
+        );
+        assert_eq!(
+            format!("{:.3}", RGAS.display_with(&options)),
+            "8.314 J/mol/K"
+        );
+        let aligned = options.clone().align_prefix(true);
+        assert_eq!(
+            format!("{:.3}", RGAS.display_with(&aligned)),
+            format!("{RGAS:.3}")
+        );
+
+        let options = FormatOptions::new().significant_digits(3);
+        assert_eq!(
+            format!("{}", (999.96 * PASCAL).display_with(&options)),
+            "1.00 kPa"
+        );
+        assert_eq!(
+            format!("{}", (0.012345 * KELVIN).display_with(&options)),
+            "0.0123 K"
+        );
+        assert_eq!(
+            format!("{}", (2.0e5 * KELVIN).display_with(&options)),
+            "2.00e5 K"
+        );
+
+        let options = options
+            .notation(Notation::Engineering)
+            .prefix(PrefixPolicy::Off);
+        assert_eq!(
+            format!("{}", (0.012345 * METER).display_with(&options)),
+            "12.3e-3 m"
+        );
+        let options = options.notation(Notation::Scientific);
+        assert_eq!(
+            format!("{}", (0.012345 * METER).display_with(&options)),
+            "1.23e-2 m"
+        );
+
+        let options = FormatOptions::new()
+            .prefix(PrefixPolicy::Forced(MILLI))
+            .ascii(true);
+        assert_eq!(
+            format!("{}", (2.5 * OHM).display_with(&options)),
+            "2500 mOhm"
+        );
+        assert_eq!(
+            format!("{}", (0.5 * KELVIN).display_with(&options)),
+            "500 mK"
+        );
+        let m3 = METER * METER * METER;
+        let options = FormatOptions::new().prefix(PrefixPolicy::Forced(KILO));
+        assert_eq!(
+            format!("{}", (2.0e6 * METER * METER).display_with(&options)),
+            "2 km²"
+        );
+        assert_eq!(
+            format!("{}", (3.0e9 * m3 / MOL).display_with(&options)),
+            "3 km³/mol"
+        );
+        let options = FormatOptions::new().prefix(PrefixPolicy::Forced(CENTI));
+        assert_eq!(format!("{}", m3.display_with(&options)), "1e6 cm³");
+        assert_eq!(format!("{}", (0.5 * METER).display_with(&options)), "50 cm");
+        for p in [2.0, 500.0, 3000.0] {
+            let options = FormatOptions::new().prefix(PrefixPolicy::Forced(p));
+            assert_eq!(format!("{}", m3.display_with(&options)), "1 m³");
+            assert_eq!(
+                format!("{}", (1500.0 * PASCAL).display_with(&options)),
+                "1500 Pa"
+            );
+        }
+        let options = FormatOptions::new().ascii(true).separator("_");
+        assert_eq!(format!("{:>8}", m3.display_with(&options)), "   1_m^3");
+    }
+
+    #[test]
+    #[cfg(feature = "ndarray")]
+    fn test_fmt_options_arr() {
+        let options = FormatOptions::new().significant_digits(2);
+        let p = arr1(&[1500.0, 25000.0]) * PASCAL;
+        assert_eq!(format!("{}", p.display_with(&options)), "[1.5, 25] kPa");
+        let options = FormatOptions::new().prefix(PrefixPolicy::Off).precision(1);
+        assert_eq!(format!("{}", p.display_with(&options)), "[1.5e3, 2.5e4] Pa");
+        let options = options.notation(Notation::Decimal);
+        assert_eq!(
+            format!("{}", p.display_with(&options)),
+            "[1500.0, 25000.0] Pa"
+        );
+        let t = ndarray::arr2(&[[1.0, 2.0], [3.0, 4.0]]) * KELVIN;
+        assert_eq!(
+            format!("{}", t.display_with(&options)),
+            "[[1.0, 2.0], [3.0, 4.0]] K"
+        );
+        let options = FormatOptions::new().prefix(PrefixPolicy::Forced(KILO));
+        let a = arr1(&[1e6, 2e6]) * METER * METER;
+        assert_eq!(format!("{}", a.display_with(&options)), "[1, 2] km²");
+    }
+
     #[test]
     fn test_fmt_angle() {
         assert_eq!(format!("{}", 90.0 * DEGREES), "90°");
@@ -0,0 +1,464 @@
+//! One-dimensional interpolation of quantity arrays.
+//!
+//! An [Interpolator] is constructed from sampling points $x_i$ and values $y_i$, that can have
+//! arbitrary units. It evaluates the interpolant and its derivative at scalar quantities or
+//! arrays of quantities with the unit of $x$ and returns quantities with the unit of $y$ or
+//! $\frac{y}{x}$, respectively.
+//!
+//! # Example
+//! ```
+//! # use quantity::*;
+//! # use quantity::interpolate::{Extrapolation, Interpolator};
+//! # use ndarray::{arr1, Array1};
+//! # use approx::assert_relative_eq;
+//! let t = Temperature::<Array1<f64>>::linspace(300.0 * KELVIN, 400.0 * KELVIN, 3);
+//! let p = arr1(&[3.5, 47.4, 245.8]) * KILO * PASCAL;
+//! let p_sat = Interpolator::pchip(&t, &p).unwrap();
+//! assert_relative_eq!(p_sat.eval(350.0 * KELVIN).unwrap(), 47.4 * KILO * PASCAL);
+//! assert!(p_sat.eval(450.0 * KELVIN).is_err());
+//!
+//! let p_sat = p_sat.extrapolation(Extrapolation::Clamp);
+//! assert_relative_eq!(p_sat.eval(450.0 * KELVIN).unwrap(), 245.8 * KILO * PASCAL);
+//! ```
+use super::{Diff, Quantity};
+use ndarray::{Array, Array1, ArrayBase, ArrayView1, Data, Dimension, Ix1};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Sub;
+
+/// Error type for the construction and evaluation of an [Interpolator].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InterpolationError {
+    /// The sampling points and values have different lengths.
+    LengthMismatch,
+    /// Less than two sampling points were provided.
+    TooFewPoints,
+    /// The sampling points are not strictly increasing.
+    NotStrictlyIncreasing,
+    /// The interpolant was evaluated outside of the sampling points with
+    /// [Extrapolation::Error].
+    OutOfBounds,
+}
+
+impl fmt::Display for InterpolationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthMismatch => write!(f, "Sampling points and values have different lengths."),
+            Self::TooFewPoints => write!(f, "At least two sampling points are required."),
+            Self::NotStrictlyIncreasing => {
+                write!(f, "The sampling points have to be strictly increasing.")
+            }
+            Self::OutOfBounds => write!(f, "The value is outside of the sampling points."),
+        }
+    }
+}
+
+impl std::error::Error for InterpolationError {}
+
+/// Behavior of an [Interpolator] outside of the sampling points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Extrapolation {
+    /// Return an [InterpolationError::OutOfBounds].
+    #[default]
+    Error,
+    /// Return the value at the closest sampling point (and a vanishing derivative).
+    Clamp,
+    /// Continue the first or last segment of the interpolant.
+    Extrapolate,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Method {
+    Linear,
+    Hermite,
+}
+
+/// Interpolant of values with unit `UY` at sampling points with unit `UX`.
+///
+/// The interpolant is either piecewise linear ([Interpolator::linear]), a monotone piecewise
+/// cubic Hermite polynomial ([Interpolator::pchip]) or a natural cubic spline
+/// ([Interpolator::cubic_spline]).
+#[derive(Clone)]
+pub struct Interpolator<UX, UY> {
+    x: Array1<f64>,
+    y: Array1<f64>,
+    slopes: Array1<f64>,
+    method: Method,
+    extrapolation: Extrapolation,
+    units: PhantomData<(UX, UY)>,
+}
+
+impl<UX, UY> Interpolator<UX, UY> {
+    fn new<S1: Data<Elem = f64>, S2: Data<Elem = f64>>(
+        x: &Quantity<ArrayBase<S1, Ix1>, UX>,
+        y: &Quantity<ArrayBase<S2, Ix1>, UY>,
+        method: Method,
+    ) -> Result<Self, InterpolationError> {
+        let x = x.0.as_standard_layout().into_owned();
+        let y = y.0.as_standard_layout().into_owned();
+        if x.len() != y.len() {
+            return Err(InterpolationError::LengthMismatch);
+        }
+        if x.len() < 2 {
+            return Err(InterpolationError::TooFewPoints);
+        }
+        if x.windows(2).into_iter().any(|w| w[1] <= w[0]) {
+            return Err(InterpolationError::NotStrictlyIncreasing);
+        }
+        let slopes = Array1::zeros(x.len());
+        Ok(Self {
+            x,
+            y,
+            slopes,
+            method,
+            extrapolation: Extrapolation::default(),
+            units: PhantomData,
+        })
+    }
+
+    /// Create a piecewise linear interpolant.
+    ///
+    /// Errors if `x` and `y` have different lengths, if there are less than two
+    /// sampling points or if `x` is not strictly increasing.
+    pub fn linear<S1: Data<Elem = f64>, S2: Data<Elem = f64>>(
+        x: &Quantity<ArrayBase<S1, Ix1>, UX>,
+        y: &Quantity<ArrayBase<S2, Ix1>, UY>,
+    ) -> Result<Self, InterpolationError> {
+        Self::new(x, y, Method::Linear)
+    }
+
+    /// Create a monotone piecewise cubic Hermite interpolant (PCHIP).
+    ///
+    /// The derivatives at the sampling points are determined with the method of
+    /// Fritsch and Carlson, so that the interpolant preserves the monotonicity of the data.
+    ///
+    /// Errors if `x` and `y` have different lengths, if there are less than two
+    /// sampling points or if `x` is not strictly increasing.
+    pub fn pchip<S1: Data<Elem = f64>, S2: Data<Elem = f64>>(
+        x: &Quantity<ArrayBase<S1, Ix1>, UX>,
+        y: &Quantity<ArrayBase<S2, Ix1>, UY>,
+    ) -> Result<Self, InterpolationError> {
+        let mut interpolator = Self::new(x, y, Method::Hermite)?;
+        interpolator.slopes = pchip_slopes(interpolator.x.view(), interpolator.y.view());
+        Ok(interpolator)
+    }
+
+    /// Create a natural cubic spline, i.e., a cubic spline with vanishing second
+    /// derivatives at the first and last sampling point.
+    ///
+    /// Errors if `x` and `y` have different lengths, if there are less than two
+    /// sampling points or if `x` is not strictly increasing.
+    pub fn cubic_spline<S1: Data<Elem = f64>, S2: Data<Elem = f64>>(
+        x: &Quantity<ArrayBase<S1, Ix1>, UX>,
+        y: &Quantity<ArrayBase<S2, Ix1>, UY>,
+    ) -> Result<Self, InterpolationError> {
+        let mut interpolator = Self::new(x, y, Method::Hermite)?;
+        interpolator.slopes = spline_slopes(interpolator.x.view(), interpolator.y.view());
+        Ok(interpolator)
+    }
+
+    /// Set the behavior of the interpolant outside of the sampling points.
+    pub fn extrapolation(mut self, extrapolation: Extrapolation) -> Self {
+        self.extrapolation = extrapolation;
+        self
+    }
+
+    /// Evaluate the interpolant at `x`.
+    ///
+    /// Errors if `x` is outside of the sampling points and the extrapolation is set to
+    /// [Extrapolation::Error].
+    pub fn eval(&self, x: Quantity<f64, UX>) -> Result<Quantity<f64, UY>, InterpolationError> {
+        self.eval_value(x.0).map(Quantity::new)
+    }
+
+    /// Evaluate the interpolant at every element of `x`.
+    ///
+    /// Errors if any element of `x` is outside of the sampling points and the extrapolation
+    /// is set to [Extrapolation::Error].
+    pub fn eval_array<S: Data<Elem = f64>, D: Dimension>(
+        &self,
+        x: &Quantity<ArrayBase<S, D>, UX>,
+    ) -> Result<Quantity<Array<f64, D>, UY>, InterpolationError> {
+        eval_array(&x.0, |x| self.eval_value(x)).map(Quantity::new)
+    }
+
+    /// Evaluate the derivative of the interpolant at `x`.
+    ///
+    /// Errors if `x` is outside of the sampling points and the extrapolation is set to
+    /// [Extrapolation::Error].
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::*;
+    /// # use quantity::interpolate::Interpolator;
+    /// # use ndarray::arr1;
+    /// # use approx::assert_relative_eq;
+    /// let t = arr1(&[0.0, 1.0, 2.0, 3.0]) * SECOND;
+    /// let x = arr1(&[0.0, 1.0, 8.0, 27.0]) * METER;
+    /// let x_t = Interpolator::linear(&t, &x).unwrap();
+    /// assert_relative_eq!(x_t.eval(1.5 * SECOND).unwrap(), 4.5 * METER);
+    /// assert_relative_eq!(x_t.derivative(1.5 * SECOND).unwrap(), 7.0 * METER / SECOND);
+    /// ```
+    pub fn derivative(
+        &self,
+        x: Quantity<f64, UX>,
+    ) -> Result<Quantity<f64, Diff<UY, UX>>, InterpolationError>
+    where
+        UY: Sub<UX>,
+    {
+        self.derivative_value(x.0).map(Quantity::new)
+    }
+
+    /// Evaluate the derivative of the interpolant at every element of `x`.
+    ///
+    /// Errors if any element of `x` is outside of the sampling points and the extrapolation
+    /// is set to [Extrapolation::Error].
+    #[expect(clippy::type_complexity)]
+    pub fn derivative_array<S: Data<Elem = f64>, D: Dimension>(
+        &self,
+        x: &Quantity<ArrayBase<S, D>, UX>,
+    ) -> Result<Quantity<Array<f64, D>, Diff<UY, UX>>, InterpolationError>
+    where
+        UY: Sub<UX>,
+    {
+        eval_array(&x.0, |x| self.derivative_value(x)).map(Quantity::new)
+    }
+
+    /// Return the interval that is used to evaluate the interpolant at `x` and whether
+    /// `x` is clamped to a sampling point.
+    fn interval(&self, x: f64) -> Result<(usize, f64, bool), InterpolationError> {
+        let n = self.x.len();
+        let (first, last) = (self.x[0], self.x[n - 1]);
+        if (first..=last).contains(&x) || self.extrapolation == Extrapolation::Extrapolate {
+            let i = self.x.as_slice().unwrap().partition_point(|&xi| xi <= x);
+            return Ok((i.clamp(1, n - 1) - 1, x, false));
+        }
+        match self.extrapolation {
+            Extrapolation::Clamp if x < first => Ok((0, first, true)),
+            Extrapolation::Clamp if x > last => Ok((n - 2, last, true)),
+            _ => Err(InterpolationError::OutOfBounds),
+        }
+    }
+
+    fn eval_value(&self, x: f64) -> Result<f64, InterpolationError> {
+        let (i, x, _) = self.interval(x)?;
+        let h = self.x[i + 1] - self.x[i];
+        let t = (x - self.x[i]) / h;
+        let (y0, y1) = (self.y[i], self.y[i + 1]);
+        Ok(match self.method {
+            Method::Linear => y0 + (y1 - y0) * t,
+            Method::Hermite => {
+                let (d0, d1) = (self.slopes[i], self.slopes[i + 1]);
+                let h00 = (1.0 + 2.0 * t) * (1.0 - t).powi(2);
+                let h10 = t * (1.0 - t).powi(2);
+                let h01 = t * t * (3.0 - 2.0 * t);
+                let h11 = t * t * (t - 1.0);
+                h00 * y0 + h10 * h * d0 + h01 * y1 + h11 * h * d1
+            }
+        })
+    }
+
+    fn derivative_value(&self, x: f64) -> Result<f64, InterpolationError> {
+        let (i, x, clamped) = self.interval(x)?;
+        if clamped {
+            return Ok(0.0);
+        }
+        let h = self.x[i + 1] - self.x[i];
+        let t = (x - self.x[i]) / h;
+        let (y0, y1) = (self.y[i], self.y[i + 1]);
+        Ok(match self.method {
+            Method::Linear => (y1 - y0) / h,
+            Method::Hermite => {
+                let (d0, d1) = (self.slopes[i], self.slopes[i + 1]);
+                let dh00 = 6.0 * t * (t - 1.0);
+                let dh10 = (3.0 * t - 1.0) * (t - 1.0);
+                let dh11 = t * (3.0 * t - 2.0);
+                dh00 * (y0 - y1) / h + dh10 * d0 + dh11 * d1
+            }
+        })
+    }
+}
+
+impl<UX, UY> fmt::Debug for Interpolator<UX, UY> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Interpolator")
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("method", &self.method)
+            .field("extrapolation", &self.extrapolation)
+            .finish()
+    }
+}
+
+fn eval_array<S: Data<Elem = f64>, D: Dimension, F: Fn(f64) -> Result<f64, InterpolationError>>(
+    x: &ArrayBase<S, D>,
+    f: F,
+) -> Result<Array<f64, D>, InterpolationError> {
+    let values = x.iter().map(|&x| f(x)).collect::<Result<Vec<_>, _>>()?;
+    Ok(Array::from_shape_vec(x.raw_dim(), values).unwrap())
+}
+
+/// Derivatives at the sampling points according to Fritsch and Carlson.
+fn pchip_slopes(x: ArrayView1<'_, f64>, y: ArrayView1<'_, f64>) -> Array1<f64> {
+    let n = x.len();
+    let h: Vec<_> = (0..n - 1).map(|i| x[i + 1] - x[i]).collect();
+    let delta: Vec<_> = (0..n - 1).map(|i| (y[i + 1] - y[i]) / h[i]).collect();
+    let mut slopes = Array1::zeros(n);
+    if n == 2 {
+        slopes.fill(delta[0]);
+        return slopes;
+    }
+    for k in 1..n - 1 {
+        if delta[k - 1] * delta[k] > 0.0 {
+            let w1 = 2.0 * h[k] + h[k - 1];
+            let w2 = h[k] + 2.0 * h[k - 1];
+            slopes[k] = (w1 + w2) / (w1 / delta[k - 1] + w2 / delta[k]);
+        }
+    }
+    slopes[0] = pchip_edge(h[0], h[1], delta[0], delta[1]);
+    slopes[n - 1] = pchip_edge(h[n - 2], h[n - 3], delta[n - 2], delta[n - 3]);
+    slopes
+}
+
+/// One-sided three-point estimate of the derivative at the boundary, that preserves monotonicity.
+fn pchip_edge(h0: f64, h1: f64, delta0: f64, delta1: f64) -> f64 {
+    let d = ((2.0 * h0 + h1) * delta0 - h0 * delta1) / (h0 + h1);
+    if d.signum() != delta0.signum() {
+        0.0
+    } else if delta0.signum() != delta1.signum() && d.abs() > 3.0 * delta0.abs() {
+        3.0 * delta0
+    } else {
+        d
+    }
+}
+
+/// Derivatives at the sampling points of a natural cubic spline.
+fn spline_slopes(x: ArrayView1<'_, f64>, y: ArrayView1<'_, f64>) -> Array1<f64> {
+    let n = x.len();
+    let h: Vec<_> = (0..n - 1).map(|i| x[i + 1] - x[i]).collect();
+    let delta: Vec<_> = (0..n - 1).map(|i| (y[i + 1] - y[i]) / h[i]).collect();
+
+    // solve the tridiagonal system for the second derivatives (Thomas algorithm)
+    let mut m = vec![0.0; n];
+    let mut c = vec![0.0; n];
+    for i in 1..n - 1 {
+        let b = 2.0 * (h[i - 1] + h[i]) - h[i - 1] * c[i - 1];
+        c[i] = h[i] / b;
+        m[i] = (6.0 * (delta[i] - delta[i - 1]) - h[i - 1] * m[i - 1]) / b;
+    }
+    for i in (1..n - 1).rev() {
+        m[i] -= c[i] * m[i + 1];
+    }
+
+    let mut slopes = Array1::zeros(n);
+    for i in 0..n - 1 {
+        slopes[i] = delta[i] - h[i] * (2.0 * m[i] + m[i + 1]) / 6.0;
+    }
+    slopes[n - 1] = delta[n - 2] + h[n - 2] * (m[n - 2] + 2.0 * m[n - 1]) / 6.0;
+    slopes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Length, METER, SECOND, Time};
+    use approx::assert_relative_eq;
+    use ndarray::{arr1, s};
+
+    #[test]
+    fn test_input_validation() {
+        let x = arr1(&[0.0, 1.0, 1.0]) * SECOND;
+        let y = arr1(&[0.0, 1.0, 2.0]) * METER;
+        assert_eq!(
+            Interpolator::linear(&x, &y).unwrap_err(),
+            InterpolationError::NotStrictlyIncreasing
+        );
+        let y = arr1(&[0.0, 1.0]) * METER;
+        assert_eq!(
+            Interpolator::pchip(&x, &y).unwrap_err(),
+            InterpolationError::LengthMismatch
+        );
+        let x = arr1(&[0.0]) * SECOND;
+        let y = arr1(&[0.0]) * METER;
+        assert_eq!(
+            Interpolator::cubic_spline(&x, &y).unwrap_err(),
+            InterpolationError::TooFewPoints
+        );
+    }
+
+    #[test]
+    fn test_extrapolation() {
+        let x = arr1(&[0.0, 1.0, 2.0]) * SECOND;
+        let y = arr1(&[0.0, 2.0, 3.0]) * METER;
+        let interpolator = Interpolator::linear(&x, &y).unwrap();
+        assert_eq!(
+            interpolator.eval(3.0 * SECOND).unwrap_err(),
+            InterpolationError::OutOfBounds
+        );
+        let interpolator = interpolator.extrapolation(Extrapolation::Extrapolate);
+        assert_relative_eq!(interpolator.eval(3.0 * SECOND).unwrap().0, 4.0);
+        assert_relative_eq!(interpolator.eval(-1.0 * SECOND).unwrap().0, -2.0);
+        let interpolator = interpolator.extrapolation(Extrapolation::Clamp);
+        assert_relative_eq!(interpolator.eval(3.0 * SECOND).unwrap().0, 3.0);
+        assert_relative_eq!(interpolator.derivative(3.0 * SECOND).unwrap().0, 0.0);
+    }
+
+    #[test]
+    fn test_cubic_spline() {
+        // a natural cubic spline reproduces a straight line and interpolates the data
+        let x = Time::<Array1<f64>>::linspace(0.0 * SECOND, 4.0 * SECOND, 5);
+        let y: Length<Array1<f64>> = x.mapv(|t| 2.0 * METER / SECOND * t + METER);
+        let spline = Interpolator::cubic_spline(&x, &y).unwrap();
+        let t = arr1(&[0.5, 1.7, 3.9]) * SECOND;
+        let y = spline.eval_array(&t).unwrap();
+        let dy = spline.derivative_array(&t).unwrap();
+        for (i, y_expected) in [2.0, 4.4, 8.8].into_iter().enumerate() {
+            assert_relative_eq!(y.0[i], y_expected, max_relative = 1e-14);
+            assert_relative_eq!(dy.0[i], 2.0, max_relative = 1e-14);
+        }
+
+        // second derivatives at the sampling points: [0, -30/7, 36/7, -30/7, 0]
+        let y = arr1(&[0.0, 1.0, 0.0, 1.0, 0.0]) * METER;
+        let spline = Interpolator::cubic_spline(&x, &y).unwrap();
+        assert_relative_eq!(
+            spline.eval(0.5 * SECOND).unwrap().0,
+            43.0 / 56.0,
+            max_relative = 1e-14
+        );
+        assert_relative_eq!(
+            spline.derivative(2.5 * SECOND).unwrap().0,
+            39.0 / 28.0,
+            max_relative = 1e-14
+        );
+    }
+
+    #[test]
+    fn test_pchip() {
+        // derivatives at the sampling points: [5/4, 3/7, 1/2, 13/4]
+        let x = arr1(&[0.0, 1.0, 3.0, 4.0]) * SECOND;
+        let y = arr1(&[0.0, 1.0, 1.5, 4.0]) * METER;
+        let pchip = Interpolator::pchip(&x, &y).unwrap();
+        assert_relative_eq!(
+            pchip.eval(2.0 * SECOND).unwrap().0,
+            69.0 / 56.0,
+            max_relative = 1e-14
+        );
+        assert_relative_eq!(
+            pchip.derivative(0.5 * SECOND).unwrap().0,
+            121.0 / 112.0,
+            max_relative = 1e-14
+        );
+    }
+
+    #[test]
+    fn test_non_contiguous() {
+        let x = arr1(&[3.0, 2.0, 1.0, 0.0]) * SECOND;
+        let y = arr1(&[6.0, 4.0, 2.0, 0.0]) * METER;
+        let interpolator = Interpolator::linear(&x.slice(s![..;-1]), &y.slice(s![..;-1])).unwrap();
+        assert_relative_eq!(interpolator.eval(1.5 * SECOND).unwrap().0, 3.0);
+        let x = arr1(&[0.0, 9.0, 1.0, 9.0, 2.0]) * SECOND;
+        let y = arr1(&[0.0, 9.0, 1.0, 9.0, 4.0]) * METER;
+        let interpolator = Interpolator::pchip(&x.slice(s![..;2]), &y.slice(s![..;2])).unwrap();
+        assert_relative_eq!(interpolator.eval(1.0 * SECOND).unwrap().0, 1.0);
+    }
+}
@@ -0,0 +1,230 @@
+use super::Quantity;
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DefaultAllocator, Dim, OMatrix, Scalar, allocator::Allocator};
+#[cfg(feature = "ndarray")]
+use ndarray::{Array, Dimension};
+use std::marker::PhantomData;
+
+/// Iterator over the elements of a quantity array, that yields clones of the elements.
+pub struct QuantityIter<I, U> {
+    inner: I,
+    unit: PhantomData<U>,
+}
+
+impl<'a, I: Iterator<Item = &'a T>, T: Clone + 'a, U> Iterator for QuantityIter<I, U> {
+    type Item = Quantity<T, U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|value| Quantity::new(value.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a T> + ExactSizeIterator, T: Clone + 'a, U> ExactSizeIterator
+    for QuantityIter<I, U>
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a T> + DoubleEndedIterator, T: Clone + 'a, U> DoubleEndedIterator
+    for QuantityIter<I, U>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next_back()
+            .map(|value| Quantity::new(value.clone()))
+    }
+}
+
+impl<'a, F, T: Clone + 'a, U> IntoIterator for &'a Quantity<F, U>
+where
+    &'a F: IntoIterator<Item = &'a T>,
+{
+    type Item = Quantity<T, U>;
+    type IntoIter = QuantityIter<<&'a F as IntoIterator>::IntoIter, U>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        QuantityIter {
+            inner: self.0.into_iter(),
+            unit: PhantomData,
+        }
+    }
+}
+
+/// Iterator over the elements of a quantity array, that yields mutable references to
+/// the elements.
+pub struct QuantityIterMut<I, U> {
+    inner: I,
+    unit: PhantomData<U>,
+}
+
+impl<'a, I: Iterator<Item = &'a mut T>, T: 'a, U: 'a> Iterator for QuantityIterMut<I, U> {
+    type Item = &'a mut Quantity<T, U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Quantity::from_mut)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a mut T> + ExactSizeIterator, T: 'a, U: 'a> ExactSizeIterator
+    for QuantityIterMut<I, U>
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a mut T> + DoubleEndedIterator, T: 'a, U: 'a> DoubleEndedIterator
+    for QuantityIterMut<I, U>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(Quantity::from_mut)
+    }
+}
+
+impl<'a, F, T: 'a, U> IntoIterator for &'a mut Quantity<F, U>
+where
+    &'a mut F: IntoIterator<Item = &'a mut T>,
+{
+    type Item = &'a mut Quantity<T, U>;
+    type IntoIter = QuantityIterMut<<&'a mut F as IntoIterator>::IntoIter, U>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        QuantityIterMut {
+            inner: self.0.into_iter(),
+            unit: PhantomData,
+        }
+    }
+}
+
+#[cfg(any(feature = "ndarray", feature = "nalgebra"))]
+/// Iterator that moves the elements out of a quantity array.
+pub struct QuantityIntoIter<I, U> {
+    inner: I,
+    unit: PhantomData<U>,
+}
+
+#[cfg(any(feature = "ndarray", feature = "nalgebra"))]
+impl<I: Iterator, U> Iterator for QuantityIntoIter<I, U> {
+    type Item = Quantity<I::Item, U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Quantity::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(any(feature = "ndarray", feature = "nalgebra"))]
+impl<I: ExactSizeIterator, U> ExactSizeIterator for QuantityIntoIter<I, U> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(any(feature = "ndarray", feature = "nalgebra"))]
+impl<I: DoubleEndedIterator, U> DoubleEndedIterator for QuantityIntoIter<I, U> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(Quantity::new)
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T, D: Dimension, U> IntoIterator for Quantity<Array<T, D>, U> {
+    type Item = Quantity<T, U>;
+    type IntoIter = QuantityIntoIter<ndarray::iter::IntoIter<T, D>, U>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        QuantityIntoIter {
+            inner: self.0.into_iter(),
+            unit: PhantomData,
+        }
+    }
+}
+
+/// As nalgebra does not provide an owning iterator, the elements of the matrix are
+/// cloned into a [Vec] first.
+#[cfg(feature = "nalgebra")]
+impl<T: Scalar, R: Dim, C: Dim, U> IntoIterator for Quantity<OMatrix<T, R, C>, U>
+where
+    DefaultAllocator: Allocator<R, C>,
+{
+    type Item = Quantity<T, U>;
+    type IntoIter = QuantityIntoIter<std::vec::IntoIter<T>, U>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        QuantityIntoIter {
+            inner: self.0.iter().cloned().collect::<Vec<_>>().into_iter(),
+            unit: PhantomData,
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "ndarray", feature = "nalgebra")))]
+mod tests {
+    use crate::METER;
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_iter_ndarray() {
+        use ndarray::arr2;
+
+        let mut x = arr2(&[[1.0, 2.0], [3.0, 4.0]]) * METER;
+        for xi in &mut x {
+            *xi += 1.0 * METER;
+        }
+        let y: Vec<_> = (&x).into_iter().map(|xi| xi.convert_into(METER)).collect();
+        assert_eq!(y, vec![2.0, 3.0, 4.0, 5.0]);
+        let z: Vec<_> = x.into_iter().map(|xi| xi / METER).collect();
+        assert_eq!(z.len(), 4);
+        assert_eq!(z[3].into_value(), 5.0);
+    }
+
+    #[cfg(all(feature = "ndarray", feature = "num-dual"))]
+    #[test]
+    fn test_iter_ndarray_dual() {
+        use crate::{KELVIN, Quantity, Temperature};
+        use ndarray::arr1;
+        use num_dual::{Dual64, DualSVec64, DualStruct};
+
+        let t: Temperature<_> =
+            Quantity::new(arr1(&[Dual64::new(300.0, 1.0), Dual64::new(350.0, 2.0)]));
+        let derivatives: Vec<_> = (&t).into_iter().map(|ti| ti.0.eps).collect();
+        assert_eq!(derivatives, vec![1.0, 2.0]);
+        let values: Vec<_> = t
+            .into_iter()
+            .map(|ti| ti.re().convert_into(KELVIN))
+            .collect();
+        assert_eq!(values, vec![300.0, 350.0]);
+
+        let t: Temperature<_> = Quantity::new(arr1(&[DualSVec64::<2>::from_re(300.0)]));
+        let t: Vec<_> = t.into_iter().collect();
+        assert_eq!(t[0].re(), 300.0 * KELVIN);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_iter_nalgebra() {
+        use crate::KELVIN;
+        use nalgebra::{dvector, vector};
+
+        let mut x = dvector![1.0, 2.0, 3.0] * KELVIN;
+        (&mut x).into_iter().for_each(|xi| *xi *= 2.0);
+        let y: Vec<_> = x.into_iter().map(|xi| xi.convert_into(KELVIN)).collect();
+        assert_eq!(y, vec![2.0, 4.0, 6.0]);
+
+        let v = vector![1.0, 2.0] * METER;
+        assert_eq!(v.into_iter().len(), 2);
+    }
+}
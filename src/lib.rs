@@ -37,6 +37,17 @@
 //! [WEBER] | $\text{Wb}$ | magnetic flux | $\text{V}\text{s}$
 //! [TESLA] | $\text{T}$ | magnetic flux density | $\\frac{\text{Wb}}{\text{m}^2}$
 //! [HENRY] | $\text{H}$ | inductance | $\\frac{\text{Wb}}{\text{A}}$
+//! [STERADIAN] | $\text{sr}$ | solid angle | $\\frac{\text{m}^2}{\text{m}^2}$
+//! [LUMEN] | $\text{lm}$ | luminous flux | $\text{cd}\\cdot\text{sr}$
+//! [LUX] | $\text{lx}$ | illuminance | $\\frac{\text{lm}}{\text{m}^2}$
+//! [BECQUEREL] | $\text{Bq}$ | activity of a radionuclide | $\text{s}^{-1}$
+//! [GRAY] | $\text{Gy}$ | absorbed dose | $\\frac{\text{J}}{\text{kg}}$
+//! [SIEVERT] | $\text{Sv}$ | equivalent dose | $\\frac{\text{J}}{\text{kg}}$
+//! [KATAL] | $\text{kat}$ | catalytic activity | $\\frac{\text{mol}}{\text{s}}$
+//!
+//! Because the representation of quantities is unique, units with the same dimension are formatted identically:
+//! steradians are dimensionless, lumen are formatted as $\text{cd}$, Becquerel as $\text{Hz}$, Gray and Sievert as
+//! $\\frac{\text{J}}{\text{kg}}$, and katal as $\\frac{\text{mol}}{\text{s}}$.
 //!
 //! ## Additional units
 //!
@@ -148,17 +159,40 @@ use ndarray::{Array, ArrayBase, Data, Dimension};
 use std::f64::consts::FRAC_1_PI;
 use std::marker::PhantomData;
 use std::ops::{Add, Deref, Div, Mul, Neg, Sub};
+use std::time::{Duration, TryFromFloatSecsError};
 
 #[cfg(feature = "num-dual")]
 pub mod ad;
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
 #[cfg(feature = "ndarray")]
 mod array;
 mod fmt;
+#[cfg(feature = "ndarray")]
+pub mod interpolate;
+mod iter;
+pub mod logarithmic;
 #[cfg(feature = "nalgebra")]
 mod nalgebra;
+pub mod ode;
 mod ops;
+pub mod optimize;
+#[cfg(feature = "rayon")]
+mod parallel;
 #[cfg(feature = "python")]
 mod python;
+#[cfg(feature = "rand")]
+pub mod random;
+#[cfg(feature = "nalgebra")]
+pub mod state;
+
+#[cfg(feature = "nalgebra")]
+pub use self::nalgebra::{QuantityCholesky, QuantityLU};
+#[cfg(feature = "ndarray")]
+pub use array::ArrayOperand;
+pub use fmt::{FormatOptions, FormattedQuantity, Notation, PrefixPolicy};
+#[cfg(feature = "rayon")]
+pub use parallel::ParallelZip;
 
 type Sum<T1, T2> = <T1 as Add<T2>>::Output;
 type Diff<T1, T2> = <T1 as Sub<T2>>::Output;
@@ -419,6 +453,20 @@ pub type _MagneticFluxDensity = Diff<_MagneticFlux, _Area>;
 pub type MagneticFluxDensity<T = f64> = Quantity<T, _MagneticFluxDensity>;
 pub type _Inductance = Diff<_MagneticFlux, _Current>;
 pub type Inductance<T = f64> = Quantity<T, _Inductance>;
+pub type _SolidAngle = _Dimensionless;
+pub type SolidAngle<T = f64> = Quantity<T, _SolidAngle>;
+pub type _LuminousFlux = Sum<_LuminousIntensity, _SolidAngle>;
+pub type LuminousFlux<T = f64> = Quantity<T, _LuminousFlux>;
+pub type _Illuminance = Diff<_LuminousFlux, _Area>;
+pub type Illuminance<T = f64> = Quantity<T, _Illuminance>;
+pub type _Radioactivity = _Frequency;
+pub type Radioactivity<T = f64> = Quantity<T, _Radioactivity>;
+pub type _AbsorbedDose = Diff<_Energy, _Mass>;
+pub type AbsorbedDose<T = f64> = Quantity<T, _AbsorbedDose>;
+pub type _EquivalentDose = _AbsorbedDose;
+pub type EquivalentDose<T = f64> = Quantity<T, _EquivalentDose>;
+pub type _CatalyticActivity = Diff<_Moles, _Time>;
+pub type CatalyticActivity<T = f64> = Quantity<T, _CatalyticActivity>;
 
 pub type _Entropy = Diff<_Energy, _Temperature>;
 pub type Entropy<T = f64> = Quantity<T, _Entropy>;
@@ -515,6 +563,20 @@ pub const WEBER: MagneticFlux = Quantity::new(1.0);
 pub const TESLA: MagneticFluxDensity = Quantity::new(1.0);
 /// Derived unit Henry $\\left(1\\,\text{T}=1\\,\\frac{\text{Wb}}{\text{A}}\\right)$
 pub const HENRY: Inductance = Quantity::new(1.0);
+/// Derived unit steradian $\\left(1\\,\text{sr}=1\\,\\frac{\text{m}^2}{\text{m}^2}\\right)$
+pub const STERADIAN: SolidAngle = Quantity::new(1.0);
+/// Derived unit lumen $\\left(1\\,\text{lm}=1\\,\text{cd}\\cdot\text{sr}\\right)$
+pub const LUMEN: LuminousFlux = Quantity::new(1.0);
+/// Derived unit lux $\\left(1\\,\text{lx}=1\\,\\frac{\text{lm}}{\text{m}^2}\\right)$
+pub const LUX: Illuminance = Quantity::new(1.0);
+/// Derived unit Becquerel $\\left(1\\,\text{Bq}=1\\,\text{s}^{-1}\\right)$
+pub const BECQUEREL: Radioactivity = Quantity::new(1.0);
+/// Derived unit Gray $\\left(1\\,\text{Gy}=1\\,\\frac{\text{J}}{\text{kg}}\\right)$
+pub const GRAY: AbsorbedDose = Quantity::new(1.0);
+/// Derived unit Sievert $\\left(1\\,\text{Sv}=1\\,\\frac{\text{J}}{\text{kg}}\\right)$
+pub const SIEVERT: EquivalentDose = Quantity::new(1.0);
+/// Derived unit katal $\\left(1\\,\text{kat}=1\\,\\frac{\text{mol}}{\text{s}}\\right)$
+pub const KATAL: CatalyticActivity = Quantity::new(1.0);
 
 /// Additional unit Ångstrom $\\left(1\\,\text{\\AA}=10^{-10}\\,\text{m}\\right)$
 pub const ANGSTROM: Length = Quantity::new(1e-10);
@@ -715,6 +777,59 @@ impl<T, U> Quantity<T, U> {
         Self(value, PhantomData)
     }
 
+    /// Reinterpret a reference to a value in SI units as a reference to a quantity
+    /// without copying.
+    pub fn from_ref(value: &T) -> &Self {
+        // SAFETY: `Quantity` is `repr(transparent)` over `T` and the lifetime of the
+        // returned reference is tied to the lifetime of `value`.
+        unsafe { &*(value as *const T as *const Self) }
+    }
+
+    /// Reinterpret a mutable reference to a value in SI units as a mutable reference to
+    /// a quantity without copying.
+    pub fn from_mut(value: &mut T) -> &mut Self {
+        // SAFETY: see `from_ref`
+        unsafe { &mut *(value as *mut T as *mut Self) }
+    }
+
+    /// Reinterpret a slice of values in SI units as a slice of quantities without copying.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Length, METER};
+    /// # use approx::assert_relative_eq;
+    /// let buffer = [1.0, 2.0, 3.0];
+    /// let x: &[Length] = Length::from_slice(&buffer);
+    /// assert_relative_eq!(x[2], 3.0 * METER);
+    /// assert_eq!(Length::as_value_slice(x), &buffer);
+    /// ```
+    pub fn from_slice(values: &[T]) -> &[Self] {
+        // SAFETY: `Quantity` is `repr(transparent)` over `T`, so `[T]` and `[Quantity<T, U>]`
+        // have the same layout and length.
+        unsafe { &*(values as *const [T] as *const [Self]) }
+    }
+
+    /// Reinterpret a mutable slice of values in SI units as a mutable slice of quantities
+    /// without copying.
+    pub fn from_mut_slice(values: &mut [T]) -> &mut [Self] {
+        // SAFETY: see `from_slice`
+        unsafe { &mut *(values as *mut [T] as *mut [Self]) }
+    }
+
+    /// Reinterpret a slice of quantities as a slice of their values in SI units without
+    /// copying.
+    pub fn as_value_slice(quantities: &[Self]) -> &[T] {
+        // SAFETY: see `from_slice`
+        unsafe { &*(quantities as *const [Self] as *const [T]) }
+    }
+
+    /// Reinterpret a mutable slice of quantities as a mutable slice of their values in
+    /// SI units without copying.
+    pub fn as_value_slice_mut(quantities: &mut [Self]) -> &mut [T] {
+        // SAFETY: see `from_slice`
+        unsafe { &mut *(quantities as *mut [Self] as *mut [T]) }
+    }
+
     /// Convert a quantity into the given unit and return it
     /// as a float or array.
     pub fn convert_to<T2>(&self, unit: Quantity<T2, U>) -> Quot<&T, T2>
@@ -742,6 +857,46 @@ impl<T> Deref for Dimensionless<T> {
     }
 }
 
+impl From<Duration> for Time {
+    fn from(duration: Duration) -> Self {
+        Quantity::new(duration.as_secs_f64())
+    }
+}
+
+impl TryFrom<Time> for Duration {
+    type Error = TryFromFloatSecsError;
+
+    /// Convert a time into a [Duration].
+    ///
+    /// Errors if the time is negative, not finite or too large to be represented by a
+    /// [Duration].
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Time, MINUTE, SECOND};
+    /// # use std::time::Duration;
+    /// let timeout = Duration::try_from(1.5 * MINUTE).unwrap();
+    /// assert_eq!(timeout, Duration::from_secs(90));
+    /// assert_eq!(Time::from(timeout), 90.0 * SECOND);
+    /// assert!(Duration::try_from(-1.0 * SECOND).is_err());
+    /// ```
+    fn try_from(time: Time) -> Result<Self, Self::Error> {
+        Duration::try_from_secs_f64(time.0)
+    }
+}
+
+// SAFETY: `Quantity` is `repr(transparent)` over `T`.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable, U> bytemuck::Zeroable for Quantity<T, U> {}
+
+// SAFETY: `Quantity` is `repr(transparent)` over `T`.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod, U: Copy + 'static> bytemuck::Pod for Quantity<T, U> {}
+
+// SAFETY: `Quantity` is `repr(transparent)` over `T`.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T, U> bytemuck::TransparentWrapper<T> for Quantity<T, U> {}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -818,4 +973,97 @@ mod test {
         let x = (pressure / PASCAL).ln();
         assert_eq!(x, 1.0135e5_f64.ln())
     }
+
+    #[test]
+    fn test_slice_reinterpretation() {
+        let mut buffer = [1.0, 2.0, 3.0];
+        let x = Length::from_mut_slice(&mut buffer);
+        x[1] *= 2.0;
+        assert_eq!(x[1].0, 4.0);
+        assert_eq!(Length::as_value_slice(x), &[1.0, 4.0, 3.0]);
+        *Length::from_mut(&mut buffer[0]) += METER;
+        assert_eq!(buffer, [2.0, 4.0, 3.0]);
+        assert_eq!(Length::from_ref(&buffer[2]).0, 3.0);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_bytemuck() {
+        let buffer = [1.0, 2.0, 3.0];
+        let bytes: &[u8] = bytemuck::cast_slice(&buffer);
+        let x: &[Length] = bytemuck::cast_slice(bytes);
+        assert_eq!(x[2].0, 3.0);
+        let zero: Pressure = bytemuck::Zeroable::zeroed();
+        assert_eq!(zero.0, 0.0);
+        let t: &[Temperature] = bytemuck::TransparentWrapper::wrap_slice(&buffer[..2]);
+        assert_eq!(t[1].0, 2.0);
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn test_approx() {
+        use approx::{abs_diff_eq, assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq};
+
+        let p = 1.0 * BAR;
+        assert_ulps_eq!(p, (1.0 + f64::EPSILON) * BAR);
+        assert_abs_diff_eq!(p, 1.000005 * BAR, epsilon = 1.0 * PASCAL);
+        assert!(!abs_diff_eq!(p, 1.00002 * BAR, epsilon = 1.0 * PASCAL));
+        assert_ulps_eq!(p, (1.0 + 1e-14) * BAR, max_ulps = 100);
+        assert_ulps_eq!(
+            p,
+            (1.0 + 1e-14) * BAR,
+            epsilon = 1e-3 * PASCAL,
+            max_ulps = 4
+        );
+        let x = 1.0 * ANGSTROM;
+        assert!(!abs_diff_eq!(x, 2.0 * ANGSTROM, epsilon = 1e-3 * ANGSTROM));
+        assert_relative_eq!(x, 1.1 * ANGSTROM, epsilon = 0.2 * ANGSTROM);
+
+        #[cfg(feature = "ndarray")]
+        {
+            let a = ndarray::arr1(&[1.0, 2.0]) * METER;
+            assert_ulps_eq!(a, ndarray::arr1(&[1.0, 2.0 + f64::EPSILON]) * METER);
+            assert_relative_eq!(a, a, epsilon = 0.0 * METER, max_relative = 0.0 * METER);
+        }
+
+        #[cfg(feature = "nalgebra")]
+        {
+            use ::nalgebra::DVector;
+            let v = DVector::from_vec(vec![1.0, 2.0]) * METER;
+            assert_ulps_eq!(v, DVector::from_vec(vec![1.0, 2.0 + f64::EPSILON]) * METER);
+            assert_abs_diff_eq!(
+                v,
+                DVector::from_vec(vec![1.0, 2.001]) * METER,
+                epsilon = MILLI * METER
+            );
+        }
+
+        #[cfg(feature = "num-dual")]
+        {
+            use num_dual::Dual64;
+            let t = Quantity::<_, _Temperature>::new(Dual64::new(300.0, 1.0));
+            let t2 = Quantity::new(Dual64::new(300.0 + 1e-9, 2.0));
+            assert_relative_eq!(t, t2, max_relative = Quantity::new(Dual64::from(1e-10)));
+            assert_ulps_eq!(t, t2, epsilon = Quantity::new(Dual64::from(1e-8)));
+            assert!(!abs_diff_eq!(
+                t,
+                t2,
+                epsilon = Quantity::new(Dual64::from(1e-10))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_duration() {
+        let t = Time::from(Duration::from_millis(1500));
+        assert_eq!(t.0, 1.5);
+        assert_eq!(
+            Duration::try_from(2.0 * HOUR + 30.0 * SECOND),
+            Ok(Duration::from_secs(7230))
+        );
+        assert_eq!(Duration::try_from(0.0 * DAY), Ok(Duration::ZERO));
+        assert!(Duration::try_from(-1.0 * MINUTE).is_err());
+        assert!(Duration::try_from(f64::NAN * SECOND).is_err());
+        assert!(Duration::try_from(f64::INFINITY * SECOND).is_err());
+    }
 }
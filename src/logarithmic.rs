@@ -0,0 +1,496 @@
+//! Logarithmic quantities like levels in decibel or neper and pH-style p-functions.
+//!
+//! A [Gain] is the logarithm of the ratio of two quantities with the same unit, e.g.,
+//! the gain of an amplifier or the attenuation of a filter. A [Level] is the logarithm of
+//! the ratio of a quantity and a fixed [Reference], e.g., a sound pressure level relative
+//! to $20\\,\text{µPa}$ or a power level in dBm relative to $1\\,\text{mW}$.
+//!
+//! Whether a ratio of $10$ corresponds to $10\\,\text{dB}$ or $20\\,\text{dB}$ depends on
+//! the kind of the quantities: ratios of power quantities (power, energy, intensity, but
+//! also concentrations) are converted with $10\log_{10}$, ratios of root-power quantities
+//! (pressure, voltage, current) with $20\log_{10}$. The kind of a level is determined by its
+//! reference. Internally, all values are stored in neper, i.e., as the natural logarithm of
+//! the ratio of root-power quantities.
+//!
+//! Adding gains or adding a gain to a level corresponds to multiplying the underlying ratios
+//! or quantities. The difference of two levels is a gain.
+//!
+//! Gains and levels are displayed in $\text{dB}$ or, with the alternate flag (`{:#}`),
+//! in $\text{Np}$.
+//!
+//! # Example
+//! ```
+//! # use quantity::{MILLI, WATT};
+//! # use quantity::logarithmic::{Gain, Level, DBM};
+//! # use approx::assert_relative_eq;
+//! let input = Level::new(2.0 * MILLI * WATT, DBM);
+//! assert_eq!(format!("{input:.2}"), "3.01 dBm");
+//! let output = input + Gain::from_db(20.0) - Gain::from_db(3.0);
+//! assert_eq!(format!("{output:.2}"), "20.01 dBm");
+//! assert_relative_eq!(output.quantity().convert_into(MILLI * WATT), 100.2374, max_relative = 1e-6);
+//! assert_eq!(format!("{:.1}", output - input), "17.0 dB");
+//! ```
+use super::{_Density, _ElectricPotential, _Power, _Pressure, Quantity};
+use std::cmp::Ordering;
+use std::f64::consts::LN_10;
+use std::fmt;
+use std::iter::Sum;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Number of decibel per neper $\\left(\frac{20}{\ln 10}\\right)$.
+const DB_PER_NP: f64 = 20.0 / LN_10;
+
+/// Logarithmic ratio of two quantities with the same unit.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
+pub struct Gain(f64);
+
+impl Gain {
+    /// Create a gain from a value in decibel.
+    pub fn from_db(db: f64) -> Self {
+        Self(db / DB_PER_NP)
+    }
+
+    /// Create a gain from a value in neper.
+    pub const fn from_np(np: f64) -> Self {
+        Self(np)
+    }
+
+    /// Create a gain from a ratio of power quantities.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::logarithmic::Gain;
+    /// # use approx::assert_relative_eq;
+    /// assert_relative_eq!(Gain::from_power_ratio(100.0).db(), 20.0);
+    /// ```
+    pub fn from_power_ratio(ratio: f64) -> Self {
+        Self(0.5 * ratio.ln())
+    }
+
+    /// Create a gain from a ratio of root-power quantities.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::logarithmic::Gain;
+    /// # use approx::assert_relative_eq;
+    /// assert_relative_eq!(Gain::from_root_power_ratio(100.0).db(), 40.0);
+    /// ```
+    pub fn from_root_power_ratio(ratio: f64) -> Self {
+        Self(ratio.ln())
+    }
+
+    /// Return the gain in decibel.
+    pub fn db(self) -> f64 {
+        self.0 * DB_PER_NP
+    }
+
+    /// Return the gain in neper.
+    pub fn np(self) -> f64 {
+        self.0
+    }
+
+    /// Return the corresponding ratio of power quantities.
+    pub fn power_ratio(self) -> f64 {
+        (2.0 * self.0).exp()
+    }
+
+    /// Return the corresponding ratio of root-power quantities.
+    pub fn root_power_ratio(self) -> f64 {
+        self.0.exp()
+    }
+}
+
+impl Add for Gain {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Gain {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl AddAssign for Gain {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0
+    }
+}
+
+impl SubAssign for Gain {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0
+    }
+}
+
+impl Neg for Gain {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Mul<f64> for Gain {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Mul<Gain> for f64 {
+    type Output = Gain;
+
+    fn mul(self, rhs: Gain) -> Gain {
+        Gain(self * rhs.0)
+    }
+}
+
+impl Sum for Gain {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|g| g.0).sum())
+    }
+}
+
+impl fmt::Display for Gain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            self.np().fmt(f)?;
+            write!(f, " Np")
+        } else {
+            self.db().fmt(f)?;
+            write!(f, " dB")
+        }
+    }
+}
+
+/// Reference value of a [Level].
+pub struct Reference<U> {
+    value: f64,
+    unit: PhantomData<U>,
+    root_power: bool,
+    suffix: &'static str,
+}
+
+impl<U> Clone for Reference<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U> Copy for Reference<U> {}
+
+impl<U> fmt::Debug for Reference<U>
+where
+    Quantity<f64, U>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Reference")
+            .field("value", &self.value())
+            .field("root_power", &self.root_power)
+            .field("suffix", &self.suffix)
+            .finish()
+    }
+}
+
+impl<U> Reference<U> {
+    /// Create a reference for levels of power quantities.
+    pub const fn power(value: Quantity<f64, U>) -> Self {
+        Self {
+            value: value.0,
+            unit: PhantomData,
+            root_power: false,
+            suffix: "",
+        }
+    }
+
+    /// Create a reference for levels of root-power quantities.
+    pub const fn root_power(value: Quantity<f64, U>) -> Self {
+        Self {
+            value: value.0,
+            unit: PhantomData,
+            root_power: true,
+            suffix: "",
+        }
+    }
+
+    /// Set the suffix that is appended to "dB" when displaying levels, e.g., "m" for dBm.
+    pub const fn with_suffix(self, suffix: &'static str) -> Self {
+        Self { suffix, ..self }
+    }
+
+    /// Return the reference value.
+    pub fn value(&self) -> Quantity<f64, U> {
+        Quantity::new(self.value)
+    }
+
+    /// Return whether the reference is a root-power quantity.
+    pub fn is_root_power(&self) -> bool {
+        self.root_power
+    }
+
+    fn gain(&self, ratio: f64) -> Gain {
+        if self.root_power {
+            Gain::from_root_power_ratio(ratio)
+        } else {
+            Gain::from_power_ratio(ratio)
+        }
+    }
+
+    fn ratio(&self, gain: Gain) -> f64 {
+        if self.root_power {
+            gain.root_power_ratio()
+        } else {
+            gain.power_ratio()
+        }
+    }
+}
+
+/// Reference for sound pressure levels in air $\\left(20\\,\text{µPa}\\right)$.
+pub const SPL: Reference<_Pressure> = Reference::root_power(Quantity::new(2e-5));
+/// Reference for power levels in dBm $\\left(1\\,\text{mW}\\right)$.
+pub const DBM: Reference<_Power> = Reference::power(Quantity::new(1e-3)).with_suffix("m");
+/// Reference for power levels in dBW $\\left(1\\,\text{W}\\right)$.
+pub const DBW: Reference<_Power> = Reference::power(Quantity::new(1.0)).with_suffix("W");
+/// Reference for voltage levels in dBV $\\left(1\\,\text{V}\\right)$.
+pub const DBV: Reference<_ElectricPotential> =
+    Reference::root_power(Quantity::new(1.0)).with_suffix("V");
+/// Reference for pH-style p-functions of concentrations $\\left(1\\,\frac{\text{mol}}{\text{l}}\\right)$.
+pub const PH: Reference<_Density> = Reference::power(Quantity::new(1e3));
+
+/// Logarithmic ratio of a quantity and a fixed [Reference].
+pub struct Level<U> {
+    gain: Gain,
+    reference: Reference<U>,
+}
+
+impl<U> Clone for Level<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U> Copy for Level<U> {}
+
+impl<U> fmt::Debug for Level<U>
+where
+    Quantity<f64, U>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Level")
+            .field("gain", &self.gain)
+            .field("reference", &self.reference)
+            .finish()
+    }
+}
+
+impl<U> Level<U> {
+    /// Create the level of `quantity` relative to `reference`.
+    ///
+    /// The level of non-positive quantities is not finite.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::PASCAL;
+    /// # use quantity::logarithmic::{Level, SPL};
+    /// # use approx::assert_relative_eq;
+    /// let level = Level::new(PASCAL, SPL);
+    /// assert_relative_eq!(level.db(), 93.979, epsilon = 1e-3);
+    /// ```
+    pub fn new(quantity: Quantity<f64, U>, reference: Reference<U>) -> Self {
+        Self::from_gain(reference.gain(quantity.0 / reference.value), reference)
+    }
+
+    /// Create a level from its gain relative to `reference`.
+    pub fn from_gain(gain: Gain, reference: Reference<U>) -> Self {
+        Self { gain, reference }
+    }
+
+    /// Create a level from a value in decibel relative to `reference`.
+    pub fn from_db(db: f64, reference: Reference<U>) -> Self {
+        Self::from_gain(Gain::from_db(db), reference)
+    }
+
+    /// Create a level from a value in neper relative to `reference`.
+    pub fn from_np(np: f64, reference: Reference<U>) -> Self {
+        Self::from_gain(Gain::from_np(np), reference)
+    }
+
+    /// Create a level from the p-function $p=-\log_{10}\frac{x}{x_0}$ of the quantity.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{MOL, LITER};
+    /// # use quantity::logarithmic::{Level, PH};
+    /// # use approx::assert_relative_eq;
+    /// let ph = Level::from_p(7.4, PH);
+    /// assert_relative_eq!(ph.quantity().convert_into(MOL / LITER), 3.981e-8, max_relative = 1e-3);
+    /// let ph = Level::new(1e-3 * MOL / LITER, PH);
+    /// assert_relative_eq!(ph.p(), 3.0);
+    /// ```
+    pub fn from_p(p: f64, reference: Reference<U>) -> Self {
+        Self::new(Quantity::new(reference.value * 10f64.powf(-p)), reference)
+    }
+
+    /// Return the (linear) quantity corresponding to the level.
+    pub fn quantity(&self) -> Quantity<f64, U> {
+        Quantity::new(self.reference.value * self.reference.ratio(self.gain))
+    }
+
+    /// Return the gain relative to the reference.
+    pub fn gain(&self) -> Gain {
+        self.gain
+    }
+
+    /// Return the reference of the level.
+    pub fn reference(&self) -> Reference<U> {
+        self.reference
+    }
+
+    /// Return the level in decibel.
+    pub fn db(&self) -> f64 {
+        self.gain.db()
+    }
+
+    /// Return the level in neper.
+    pub fn np(&self) -> f64 {
+        self.gain.np()
+    }
+
+    /// Return the p-function $p=-\log_{10}\frac{x}{x_0}$ of the quantity.
+    pub fn p(&self) -> f64 {
+        -self.reference.ratio(self.gain).log10()
+    }
+}
+
+impl<U> Add<Gain> for Level<U> {
+    type Output = Self;
+
+    fn add(self, rhs: Gain) -> Self {
+        Self::from_gain(self.gain + rhs, self.reference)
+    }
+}
+
+impl<U> Add<Level<U>> for Gain {
+    type Output = Level<U>;
+
+    fn add(self, rhs: Level<U>) -> Level<U> {
+        rhs + self
+    }
+}
+
+impl<U> Sub<Gain> for Level<U> {
+    type Output = Self;
+
+    fn sub(self, rhs: Gain) -> Self {
+        Self::from_gain(self.gain - rhs, self.reference)
+    }
+}
+
+impl<U> AddAssign<Gain> for Level<U> {
+    fn add_assign(&mut self, rhs: Gain) {
+        self.gain += rhs
+    }
+}
+
+impl<U> SubAssign<Gain> for Level<U> {
+    fn sub_assign(&mut self, rhs: Gain) {
+        self.gain -= rhs
+    }
+}
+
+/// The difference of two levels is the gain between the corresponding quantities. If the
+/// levels have different references, the kind of the reference of `self` is used.
+impl<U> Sub for Level<U> {
+    type Output = Gain;
+
+    fn sub(self, rhs: Self) -> Gain {
+        if self.reference.value == rhs.reference.value
+            && self.reference.root_power == rhs.reference.root_power
+        {
+            self.gain - rhs.gain
+        } else {
+            self.reference.gain(self.quantity().0 / rhs.quantity().0)
+        }
+    }
+}
+
+impl<U> PartialEq for Level<U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.quantity().0 == other.quantity().0
+    }
+}
+
+impl<U> PartialOrd for Level<U> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.quantity().0.partial_cmp(&other.quantity().0)
+    }
+}
+
+impl<U> fmt::Display for Level<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            self.np().fmt(f)?;
+            write!(f, " Np")
+        } else {
+            self.db().fmt(f)?;
+            write!(f, " dB{}", self.reference.suffix)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MILLI, PASCAL, VOLT, WATT};
+
+    #[test]
+    fn test_gain() {
+        let g = Gain::from_db(10.0);
+        assert!((g.power_ratio() - 10.0).abs() < 1e-12);
+        assert!((g.root_power_ratio() - 10f64.sqrt()).abs() < 1e-12);
+        assert!((Gain::from_np(1.0).db() - 8.685889638065037).abs() < 1e-12);
+        let total: Gain = [g, g, -g].into_iter().sum();
+        assert!((total.db() - 10.0).abs() < 1e-12);
+        assert!(((2.0 * g).power_ratio() - 100.0).abs() < 1e-10);
+        assert_eq!(format!("{:.1}", Gain::from_db(-3.0)), "-3.0 dB");
+        assert_eq!(format!("{:#.3}", Gain::from_np(0.5)), "0.500 Np");
+    }
+
+    #[test]
+    fn test_level() {
+        let p = Level::new(2.0 * PASCAL, SPL);
+        assert!((p.db() - 100.0).abs() < 1e-12);
+        assert!((p.quantity().0 - 2.0).abs() < 1e-12);
+        let p2 = p + Gain::from_db(6.0);
+        assert!(p2 > p);
+        assert!(((p2 - p).db() - 6.0).abs() < 1e-12);
+        assert_eq!(format!("{p:.1}"), "100.0 dB");
+
+        let v = Level::new(10.0 * VOLT, DBV);
+        assert!((v.db() - 20.0).abs() < 1e-12);
+        assert_eq!(format!("{v:.0}"), "20 dBV");
+        assert_eq!(format!("{:#.4}", Level::new(VOLT, DBV)), "0.0000 Np");
+    }
+
+    #[test]
+    fn test_level_references() {
+        let mut p = Level::new(WATT, DBW);
+        assert_eq!(p.db(), 0.0);
+        p -= Gain::from_db(10.0);
+        let p_dbm = Level::new(p.quantity(), DBM);
+        assert!((p_dbm.db() - 20.0).abs() < 1e-12);
+        assert!((p_dbm - p).db().abs() < 1e-12);
+        let q = Level::from_db(10.0, DBM);
+        assert!(((p_dbm - q).db() - 10.0).abs() < 1e-12);
+        assert!((q.quantity().0 - 10.0 * MILLI).abs() < 1e-15);
+        assert!(Level::new(0.0 * WATT, DBW).db().is_infinite());
+    }
+}
@@ -1,9 +1,15 @@
-use super::{Quantity, Sum};
+use super::{Angle, Const, Diff, Negate, Prod, Quantity, Sum};
 use nalgebra::allocator::Allocator;
-use nalgebra::constraint::{DimEq, ShapeConstraint};
-use nalgebra::{ClosedAddAssign, ClosedMulAssign, DMatrix, DefaultAllocator, Dim, OMatrix, Scalar};
-use num_traits::Zero;
-use std::ops::Add;
+use nalgebra::constraint::{AreMultipliable, DimEq, ShapeConstraint};
+use nalgebra::{
+    Cholesky, ClosedAddAssign, ClosedMulAssign, ClosedSubAssign, DMatrix, DVector,
+    DefaultAllocator, Dim, DimDiff, DimMin, DimSub, LU, OMatrix, OVector, Point, Point2, Point3,
+    RealField, Rotation2, Rotation3, SVector, Scalar, U1, Unit, UnitComplex, UnitQuaternion,
+    Vector2, Vector3,
+};
+use num_traits::{One, Zero};
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Neg, Sub};
 
 impl<R: Dim, C: Dim, U, T: Scalar> Quantity<OMatrix<T, R, C>, U>
 where
@@ -36,6 +42,36 @@ where
         Quantity::new(self.0.sum())
     }
 
+    /// Return the product of all elements in the matrix.
+    ///
+    /// Because the unit of the result depends on the number of elements, it has to be
+    /// specified at compile time.
+    ///
+    /// **Panics** if `N` is negative or if the number of elements is not equal to `N`.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{METER, Volume};
+    /// # use nalgebra::vector;
+    /// # use approx::assert_relative_eq;
+    /// let edges = vector![2.0, 3.0, 4.0] * METER;
+    /// let volume: Volume = edges.product::<3>();
+    /// assert_relative_eq!(volume, 24.0 * METER.powi::<3>());
+    /// ```
+    pub fn product<const N: i8>(&self) -> Quantity<T, Prod<U, Const<N>>>
+    where
+        T: One + ClosedMulAssign,
+        U: Mul<Const<N>>,
+    {
+        assert!(N >= 0, "The exponent of the unit must not be negative.");
+        assert_eq!(
+            self.len(),
+            N as usize,
+            "The number of elements has to match the exponent of the unit."
+        );
+        Quantity::new(self.0.product())
+    }
+
     pub fn get(&self, index: usize) -> Quantity<T, U>
     where
         T: Copy,
@@ -61,6 +97,24 @@ where
     pub fn shape_generic(&self) -> (R, C) {
         self.0.shape_generic()
     }
+
+    /// Return an iterator of mutable references to the elements of the matrix in
+    /// column-major order.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Length, METER};
+    /// # use nalgebra::{vector, Vector2};
+    /// # use approx::assert_relative_eq;
+    /// let mut x: Length<Vector2<f64>> = vector![1.0, 2.0] * METER;
+    /// x.iter_mut().for_each(|xi| *xi *= 3.0);
+    /// assert_relative_eq!(x, vector![3.0, 6.0] * METER);
+    /// ```
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl ExactSizeIterator<Item = &mut Quantity<T, U>> + DoubleEndedIterator {
+        self.0.iter_mut().map(Quantity::from_mut)
+    }
 }
 
 impl<T: Scalar, R: Dim, C: Dim, U> Quantity<OMatrix<T, R, C>, U>
@@ -98,6 +152,39 @@ where
         Quantity::new(self.0.dot(&rhs.0))
     }
 
+    /// Return the matrix product of `self` and `rhs`.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{BAR, METER, Energy};
+    /// # use nalgebra::{matrix, vector, SVector};
+    /// # use approx::assert_relative_eq;
+    /// let p = matrix![1.0, 2.0; 3.0, 4.0] * BAR;
+    /// let v = vector![1.0, 1.0] * METER.powi::<3>();
+    /// let e: Energy<SVector<f64, 2>> = p.matmul(&v);
+    /// assert_relative_eq!(e.get(1), 7.0 * BAR * METER.powi::<3>());
+    /// ```
+    pub fn matmul<U2, R2: Dim, C2: Dim>(
+        &self,
+        rhs: &Quantity<OMatrix<T, R2, C2>, U2>,
+    ) -> Quantity<OMatrix<T, R, C2>, Sum<U, U2>>
+    where
+        DefaultAllocator: Allocator<R2, C2> + Allocator<R, C2>,
+        T: Zero + One + ClosedAddAssign + ClosedMulAssign,
+        U: Add<U2>,
+        ShapeConstraint: AreMultipliable<R, C, R2, C2>,
+    {
+        Quantity::new(&self.0 * &rhs.0)
+    }
+
+    /// Return the transpose of the matrix.
+    pub fn transpose(&self) -> Quantity<OMatrix<T, C, R>, U>
+    where
+        DefaultAllocator: Allocator<C, R>,
+    {
+        Quantity::new(self.0.transpose())
+    }
+
     pub fn from_fn_generic<F>(nrows: R, ncols: C, mut f: F) -> Self
     where
         F: FnMut(usize, usize) -> Quantity<T, U>,
@@ -110,6 +197,538 @@ where
     }
 }
 
+impl<R: Dim, C: Dim, U> Quantity<OMatrix<f64, R, C>, U>
+where
+    DefaultAllocator: Allocator<R, C>,
+{
+    /// Return the arithmetic mean of all elements in the matrix or `None` if the matrix is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::KELVIN;
+    /// # use nalgebra::dvector;
+    /// # use approx::assert_relative_eq;
+    /// let t = dvector![300.0, 310.0, 350.0] * KELVIN;
+    /// assert_relative_eq!(t.mean().unwrap(), 320.0 * KELVIN);
+    /// ```
+    pub fn mean(&self) -> Option<Quantity<f64, U>> {
+        (!self.is_empty()).then(|| Quantity::new(self.0.mean()))
+    }
+
+    /// Return the variance of all elements in the matrix.
+    ///
+    /// The variance is calculated with `n - ddof` as denominator, where `n` is the number of elements.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than `n`.
+    pub fn var(&self, ddof: f64) -> Quantity<f64, Sum<U, U>>
+    where
+        U: Add<U>,
+    {
+        Quantity::new(self.variance(ddof))
+    }
+
+    /// Return the standard deviation of all elements in the matrix.
+    ///
+    /// The variance is calculated with `n - ddof` as denominator, where `n` is the number of elements.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than `n`.
+    pub fn std(&self, ddof: f64) -> Quantity<f64, U> {
+        Quantity::new(self.variance(ddof).sqrt())
+    }
+
+    fn variance(&self, ddof: f64) -> f64 {
+        let n = self.len() as f64;
+        assert!(
+            (0.0..=n).contains(&ddof),
+            "`ddof` must not be less than zero or greater than the number of elements."
+        );
+        let mean = self.0.mean();
+        let sum = self.0.iter().map(|x| (x - mean).powi(2)).sum::<f64>();
+        sum / (n - ddof)
+    }
+
+    /// Return the weighted mean of all elements in the matrix.
+    ///
+    /// The weights can be arbitrary quantities (e.g., masses or mole numbers) and have to
+    /// have the same shape as `self`.
+    pub fn weighted_mean<U2>(
+        &self,
+        weights: &Quantity<OMatrix<f64, R, C>, U2>,
+    ) -> Quantity<f64, U> {
+        Quantity::new(self.0.dot(&weights.0) / weights.0.sum())
+    }
+
+    /// Return the minimum of all elements in the matrix.
+    ///
+    /// NaN values are ignored. Returns `None` if the matrix contains no values other than NaN.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::BAR;
+    /// # use nalgebra::dvector;
+    /// # use approx::assert_relative_eq;
+    /// let p = dvector![3.0, f64::NAN, 1.0, 2.0] * BAR;
+    /// assert_relative_eq!(p.min().unwrap(), BAR);
+    /// assert_eq!(p.argmin(), Some(2));
+    /// ```
+    pub fn min(&self) -> Option<Quantity<f64, U>> {
+        self.argmin().map(|i| self.get(i))
+    }
+
+    /// Return the maximum of all elements in the matrix.
+    ///
+    /// NaN values are ignored. Returns `None` if the matrix contains no values other than NaN.
+    pub fn max(&self) -> Option<Quantity<f64, U>> {
+        self.argmax().map(|i| self.get(i))
+    }
+
+    /// Return the (column-major) index of the minimum of all elements in the matrix.
+    ///
+    /// NaN values are ignored. Returns `None` if the matrix contains no values other than NaN.
+    pub fn argmin(&self) -> Option<usize> {
+        self.arg_extremum(|x, y| x < y)
+    }
+
+    /// Return the (column-major) index of the maximum of all elements in the matrix.
+    ///
+    /// NaN values are ignored. Returns `None` if the matrix contains no values other than NaN.
+    pub fn argmax(&self) -> Option<usize> {
+        self.arg_extremum(|x, y| x > y)
+    }
+
+    fn arg_extremum<F: Fn(f64, f64) -> bool>(&self, replace: F) -> Option<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| !x.is_nan())
+            .fold(None, |acc, (i, &x)| match acc {
+                Some((_, y)) if !replace(x, y) => acc,
+                _ => Some((i, x)),
+            })
+            .map(|(i, _)| i)
+    }
+}
+
+impl<T: RealField, R: Dim, C: Dim, U> Quantity<OMatrix<T, R, C>, U>
+where
+    DefaultAllocator: Allocator<R, C>,
+{
+    /// Return the Euclidean (Frobenius) norm of the matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::METER;
+    /// # use nalgebra::vector;
+    /// # use approx::assert_relative_eq;
+    /// let r = vector![3.0, 0.0, 4.0] * METER;
+    /// assert_relative_eq!(r.norm(), 5.0 * METER);
+    /// assert_relative_eq!(r.normalize(), vector![0.6, 0.0, 0.8]);
+    /// ```
+    pub fn norm(&self) -> Quantity<T, U> {
+        Quantity::new(self.0.norm())
+    }
+
+    /// Return the (dimensionless) matrix divided by its norm.
+    pub fn normalize(&self) -> OMatrix<T, R, C> {
+        self.0.normalize()
+    }
+}
+
+impl<T: Scalar, D: Dim, U> Quantity<OMatrix<T, D, D>, U>
+where
+    DefaultAllocator: Allocator<D, D>,
+{
+    /// Return the trace of the square matrix.
+    pub fn trace(&self) -> Quantity<T, U>
+    where
+        T: Zero + ClosedAddAssign,
+    {
+        Quantity::new(self.0.trace())
+    }
+
+    /// Return the inverse of the square matrix or `None` if the matrix is not invertible.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{BAR, Pressure};
+    /// # use nalgebra::{matrix, Matrix2};
+    /// # use approx::assert_relative_eq;
+    /// let a = matrix![2.0, 1.0; 1.0, 1.0] * BAR;
+    /// let a_inv = a.try_inverse().unwrap();
+    /// assert_relative_eq!(a_inv.get2(0, 1), -1.0 / BAR);
+    /// assert_relative_eq!(a.matmul(&a_inv).into_value(), Matrix2::identity());
+    /// ```
+    pub fn try_inverse(&self) -> Option<Quantity<OMatrix<T, D, D>, Negate<U>>>
+    where
+        T: RealField,
+        U: Neg,
+    {
+        self.0.clone().try_inverse().map(Quantity::new)
+    }
+
+    /// Solve the linear system `self * x = b` using an LU decomposition.
+    ///
+    /// Returns `None` if the matrix is not invertible.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{METER, NEWTON};
+    /// # use nalgebra::{matrix, vector};
+    /// # use approx::assert_relative_eq;
+    /// // stiffness matrix and forces of a system of springs
+    /// let k = matrix![2.0, -1.0; -1.0, 2.0] * NEWTON / METER;
+    /// let f = vector![1.0, 0.0] * NEWTON;
+    /// let x = k.solve(&f).unwrap();
+    /// assert_relative_eq!(x.get(0), 2.0 / 3.0 * METER);
+    /// assert_relative_eq!(x.get(1), 1.0 / 3.0 * METER);
+    /// ```
+    #[expect(clippy::type_complexity)]
+    pub fn solve<U2, C2: Dim>(
+        &self,
+        b: &Quantity<OMatrix<T, D, C2>, U2>,
+    ) -> Option<Quantity<OMatrix<T, D, C2>, Diff<U2, U>>>
+    where
+        T: RealField,
+        D: DimMin<D, Output = D>,
+        DefaultAllocator: Allocator<D> + Allocator<D, C2>,
+        U2: Sub<U>,
+    {
+        self.lu().solve(b)
+    }
+
+    /// Return the LU decomposition of the square matrix with partial pivoting.
+    pub fn lu(&self) -> QuantityLU<T, D, U>
+    where
+        T: RealField,
+        D: DimMin<D, Output = D>,
+        DefaultAllocator: Allocator<D>,
+    {
+        QuantityLU {
+            lu: self.0.clone().lu(),
+            unit: PhantomData,
+        }
+    }
+
+    /// Return the Cholesky decomposition of the symmetric positive-definite matrix or
+    /// `None` if the matrix is not positive-definite.
+    ///
+    /// Only the lower-triangular part of the matrix is read.
+    pub fn cholesky(&self) -> Option<QuantityCholesky<T, D, U>>
+    where
+        T: RealField,
+    {
+        self.0.clone().cholesky().map(|chol| QuantityCholesky {
+            chol,
+            unit: PhantomData,
+        })
+    }
+
+    /// Return the eigenvalues of the square matrix or `None` if some eigenvalues are complex.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{JOULE, Energy};
+    /// # use nalgebra::matrix;
+    /// # use approx::assert_relative_eq;
+    /// let h = matrix![2.0, 1.0; 1.0, 2.0] * JOULE;
+    /// let mut e = h.symmetric_eigenvalues().convert_into(JOULE);
+    /// e.as_mut_slice().sort_by(f64::total_cmp);
+    /// assert_relative_eq!(e[0], 1.0, max_relative = 1e-14);
+    /// assert_relative_eq!(e[1], 3.0, max_relative = 1e-14);
+    /// assert!(h.eigenvalues().is_some());
+    /// ```
+    pub fn eigenvalues(&self) -> Option<Quantity<OVector<T, D>, U>>
+    where
+        T: RealField,
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<D, DimDiff<D, U1>> + Allocator<DimDiff<D, U1>> + Allocator<D>,
+    {
+        self.0.eigenvalues().map(Quantity::new)
+    }
+
+    /// Return the eigenvalues of the symmetric matrix.
+    ///
+    /// Only the lower-triangular part of the matrix is read.
+    pub fn symmetric_eigenvalues(&self) -> Quantity<OVector<T, D>, U>
+    where
+        T: RealField,
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<DimDiff<D, U1>> + Allocator<D>,
+    {
+        Quantity::new(self.0.symmetric_eigenvalues())
+    }
+}
+
+/// LU decomposition of a square quantity matrix with partial pivoting.
+///
+/// The decomposition can be reused to solve linear systems with different right-hand sides.
+#[derive(Clone, Debug)]
+pub struct QuantityLU<T: RealField, D: DimMin<D, Output = D>, U>
+where
+    DefaultAllocator: Allocator<D, D> + Allocator<D>,
+{
+    lu: LU<T, D, D>,
+    unit: PhantomData<U>,
+}
+
+impl<T: RealField, D: DimMin<D, Output = D>, U> QuantityLU<T, D, U>
+where
+    DefaultAllocator: Allocator<D, D> + Allocator<D>,
+{
+    /// Solve the linear system `A * x = b`, where `A` is the decomposed matrix.
+    ///
+    /// Returns `None` if the decomposed matrix is not invertible.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{KELVIN, PASCAL};
+    /// # use nalgebra::{matrix, vector};
+    /// # use approx::assert_relative_eq;
+    /// let jacobian = matrix![1.0, 0.0; 0.0, 2.0] * PASCAL / KELVIN;
+    /// let lu = jacobian.lu();
+    /// let residual = vector![1.0, 1.0] * PASCAL;
+    /// let dt = lu.solve(&residual).unwrap();
+    /// assert_relative_eq!(dt.get(1), 0.5 * KELVIN);
+    /// let dt = lu.solve(&(2.0 * residual)).unwrap();
+    /// assert_relative_eq!(dt.get(1), KELVIN);
+    /// ```
+    #[expect(clippy::type_complexity)]
+    pub fn solve<U2, C2: Dim>(
+        &self,
+        b: &Quantity<OMatrix<T, D, C2>, U2>,
+    ) -> Option<Quantity<OMatrix<T, D, C2>, Diff<U2, U>>>
+    where
+        DefaultAllocator: Allocator<D, C2>,
+        U2: Sub<U>,
+    {
+        self.lu.solve(&b.0).map(Quantity::new)
+    }
+
+    /// Return the inverse of the decomposed matrix or `None` if it is not invertible.
+    pub fn try_inverse(&self) -> Option<Quantity<OMatrix<T, D, D>, Negate<U>>>
+    where
+        U: Neg,
+    {
+        self.lu.try_inverse().map(Quantity::new)
+    }
+}
+
+/// Cholesky decomposition of a symmetric positive-definite quantity matrix.
+///
+/// The decomposition can be reused to solve linear systems with different right-hand sides.
+#[derive(Clone, Debug)]
+pub struct QuantityCholesky<T: RealField, D: Dim, U>
+where
+    DefaultAllocator: Allocator<D, D>,
+{
+    chol: Cholesky<T, D>,
+    unit: PhantomData<U>,
+}
+
+impl<T: RealField, D: Dim, U> QuantityCholesky<T, D, U>
+where
+    DefaultAllocator: Allocator<D, D>,
+{
+    /// Solve the linear system `A * x = b`, where `A` is the decomposed matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{METER, NEWTON};
+    /// # use nalgebra::{matrix, vector};
+    /// # use approx::assert_relative_eq;
+    /// let k = matrix![2.0, -1.0; -1.0, 2.0] * NEWTON / METER;
+    /// let x = k.cholesky().unwrap().solve(&(vector![1.0, 0.0] * NEWTON));
+    /// assert_relative_eq!(x.get(0), 2.0 / 3.0 * METER);
+    /// ```
+    pub fn solve<U2, C2: Dim>(
+        &self,
+        b: &Quantity<OMatrix<T, D, C2>, U2>,
+    ) -> Quantity<OMatrix<T, D, C2>, Diff<U2, U>>
+    where
+        DefaultAllocator: Allocator<D, C2>,
+        U2: Sub<U>,
+    {
+        Quantity::new(self.chol.solve(&b.0))
+    }
+
+    /// Return the inverse of the decomposed matrix.
+    pub fn inverse(&self) -> Quantity<OMatrix<T, D, D>, Negate<U>>
+    where
+        U: Neg,
+    {
+        Quantity::new(self.chol.inverse())
+    }
+}
+
+impl<T: Scalar, U> Quantity<Vector3<T>, U> {
+    /// Return the cross product of two three-dimensional vectors.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{METER, NEWTON};
+    /// # use nalgebra::vector;
+    /// # use approx::assert_relative_eq;
+    /// let r = vector![2.0, 0.0, 0.0] * METER;
+    /// let f = vector![0.0, 5.0, 0.0] * NEWTON;
+    /// let torque = r.cross(&f);
+    /// assert_relative_eq!(torque.get(2), 10.0 * NEWTON * METER);
+    /// ```
+    pub fn cross<U2>(&self, rhs: &Quantity<Vector3<T>, U2>) -> Quantity<Vector3<T>, Sum<U, U2>>
+    where
+        T: ClosedAddAssign + ClosedSubAssign + ClosedMulAssign,
+        U: Add<U2>,
+    {
+        Quantity::new(self.0.cross(&rhs.0))
+    }
+}
+
+impl<T: Scalar, const D: usize, U> Quantity<Point<T, D>, U> {
+    /// Create a point from the vector of its coordinates.
+    pub fn from_coords(coords: Quantity<SVector<T, D>, U>) -> Self {
+        Quantity::new(Point::from(coords.0))
+    }
+
+    /// Return the vector of coordinates of the point.
+    pub fn coords(&self) -> Quantity<SVector<T, D>, U> {
+        Quantity::new(self.0.coords.clone())
+    }
+
+    /// Return the Euclidean distance between two points.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::METER;
+    /// # use nalgebra::point;
+    /// # use approx::assert_relative_eq;
+    /// let p1 = point![1.0, 2.0, 3.0] * METER;
+    /// let p2 = point![4.0, 6.0, 3.0] * METER;
+    /// assert_relative_eq!(p1.distance(&p2), 5.0 * METER);
+    /// assert_relative_eq!((p2 - p1).norm(), 5.0 * METER);
+    /// ```
+    pub fn distance(&self, other: &Self) -> Quantity<T, U>
+    where
+        T: RealField,
+    {
+        Quantity::new(nalgebra::distance(&self.0, &other.0))
+    }
+}
+
+impl<T: RealField> Angle<T> {
+    /// Return the two-dimensional rotation by this angle.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{DEGREES, METER};
+    /// # use nalgebra::vector;
+    /// # use approx::assert_relative_eq;
+    /// let rot = (90.0 * DEGREES).rotation2();
+    /// let r = rot * (vector![1.0, 0.0] * METER);
+    /// assert_relative_eq!(r.get(1), METER);
+    /// ```
+    pub fn rotation2(self) -> Rotation2<T> {
+        Rotation2::new(self.0)
+    }
+
+    /// Return the two-dimensional rotation by this angle as a unit complex number.
+    pub fn unit_complex(self) -> UnitComplex<T> {
+        UnitComplex::new(self.0)
+    }
+
+    /// Return the three-dimensional rotation by this angle around `axis`.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{DEGREES, METER, SECOND};
+    /// # use nalgebra::{vector, Vector3};
+    /// # use approx::assert_relative_eq;
+    /// let rot = (90.0 * DEGREES).rotation3(&Vector3::z_axis());
+    /// let v = rot * (vector![1.0, 0.0, 0.0] * METER / SECOND);
+    /// assert_relative_eq!(v.get(1), METER / SECOND);
+    /// ```
+    pub fn rotation3(self, axis: &Unit<Vector3<T>>) -> Rotation3<T> {
+        Rotation3::from_axis_angle(axis, self.0)
+    }
+
+    /// Return the three-dimensional rotation by this angle around `axis` as a unit
+    /// quaternion.
+    pub fn unit_quaternion(self, axis: &Unit<Vector3<T>>) -> UnitQuaternion<T> {
+        UnitQuaternion::from_axis_angle(axis, self.0)
+    }
+}
+
+impl<T: RealField> From<Angle<T>> for Rotation2<T> {
+    fn from(angle: Angle<T>) -> Self {
+        angle.rotation2()
+    }
+}
+
+impl<T: RealField> From<Angle<T>> for UnitComplex<T> {
+    fn from(angle: Angle<T>) -> Self {
+        angle.unit_complex()
+    }
+}
+
+macro_rules! impl_rotation {
+    ($rot:ident, $t:ident) => {
+        impl<T: RealField, U> Mul<Quantity<$t<T>, U>> for $rot<T> {
+            type Output = Quantity<$t<T>, U>;
+            fn mul(self, rhs: Quantity<$t<T>, U>) -> Self::Output {
+                Quantity::new(self * rhs.0)
+            }
+        }
+
+        impl<T: RealField, U> Mul<&Quantity<$t<T>, U>> for &$rot<T> {
+            type Output = Quantity<$t<T>, U>;
+            fn mul(self, rhs: &Quantity<$t<T>, U>) -> Self::Output {
+                Quantity::new(self * &rhs.0)
+            }
+        }
+    };
+}
+
+impl_rotation!(Rotation2, Vector2);
+impl_rotation!(Rotation2, Point2);
+impl_rotation!(UnitComplex, Vector2);
+impl_rotation!(UnitComplex, Point2);
+impl_rotation!(Rotation3, Vector3);
+impl_rotation!(Rotation3, Point3);
+impl_rotation!(UnitQuaternion, Vector3);
+impl_rotation!(UnitQuaternion, Point3);
+
+impl<T: Scalar, U> Quantity<DVector<T>, U> {
+    /// Concatenate vectors.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Length, METER};
+    /// # use nalgebra::{dvector, DVector};
+    /// # use approx::assert_relative_eq;
+    /// let x1 = dvector![1.0, 2.0] * METER;
+    /// let x2 = dvector![3.0] * METER;
+    /// let x = Length::<DVector<f64>>::concatenate(&[&x1, &x2]);
+    /// assert_relative_eq!(x, dvector![1.0, 2.0, 3.0] * METER);
+    /// ```
+    pub fn concatenate(vectors: &[&Self]) -> Self {
+        let n = vectors.iter().map(|v| v.len()).sum();
+        let values = vectors.iter().flat_map(|v| v.0.iter().cloned());
+        Self::new(DVector::from_iterator(n, values))
+    }
+
+    /// Append an element to the vector.
+    pub fn push(&mut self, value: Quantity<T, U>) {
+        self.0.extend([value.0]);
+    }
+}
+
+impl<T: Scalar, U> Extend<Quantity<T, U>> for Quantity<DVector<T>, U> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Quantity<T, U>>,
+    {
+        self.0.extend(iter.into_iter().map(|v| v.0));
+    }
+}
+
 impl<T: Scalar, U> Quantity<DMatrix<T>, U> {
     pub fn from_fn<F>(nrows: usize, ncols: usize, mut f: F) -> Self
     where
@@ -117,4 +736,249 @@ impl<T: Scalar, U> Quantity<DMatrix<T>, U> {
     {
         Self::new(DMatrix::from_fn(nrows, ncols, |i, j| f(i, j).0))
     }
+
+    /// Create a matrix from a slice of column vectors.
+    ///
+    /// **Panics** if the vectors do not have the same length.
+    pub fn from_columns(columns: &[&Quantity<DVector<T>, U>]) -> Self {
+        let nrows = columns.first().map_or(0, |c| c.len());
+        assert!(
+            columns.iter().all(|c| c.len() == nrows),
+            "All columns must have the same length."
+        );
+        let values = columns.iter().flat_map(|c| c.0.iter().cloned());
+        Self::new(DMatrix::from_iterator(nrows, columns.len(), values))
+    }
+
+    /// Create a matrix from a slice of row vectors.
+    ///
+    /// **Panics** if the vectors do not have the same length.
+    pub fn from_rows(rows: &[&Quantity<DVector<T>, U>]) -> Self {
+        Self::new(Self::from_columns(rows).0.transpose())
+    }
+
+    /// Concatenate matrices horizontally (along their columns).
+    ///
+    /// **Panics** if the matrices do not have the same number of rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Pressure, BAR};
+    /// # use nalgebra::dmatrix;
+    /// # use approx::assert_relative_eq;
+    /// let p1 = dmatrix![1.0; 2.0] * BAR;
+    /// let p2 = dmatrix![3.0, 5.0; 4.0, 6.0] * BAR;
+    /// let p = Pressure::hstack(&[&p1, &p2]);
+    /// assert_relative_eq!(p, dmatrix![1.0, 3.0, 5.0; 2.0, 4.0, 6.0] * BAR);
+    /// ```
+    pub fn hstack(matrices: &[&Self]) -> Self {
+        let nrows = matrices.first().map_or(0, |m| m.0.nrows());
+        assert!(
+            matrices.iter().all(|m| m.0.nrows() == nrows),
+            "All matrices must have the same number of rows."
+        );
+        let ncols = matrices.iter().map(|m| m.0.ncols()).sum();
+        let values = matrices.iter().flat_map(|m| m.0.iter().cloned());
+        Self::new(DMatrix::from_iterator(nrows, ncols, values))
+    }
+
+    /// Concatenate matrices vertically (along their rows).
+    ///
+    /// **Panics** if the matrices do not have the same number of columns.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Pressure, BAR};
+    /// # use nalgebra::{dmatrix, dvector};
+    /// # use approx::assert_relative_eq;
+    /// let p1 = dmatrix![1.0, 2.0] * BAR;
+    /// let p2 = dmatrix![3.0, 4.0; 5.0, 6.0] * BAR;
+    /// let mut p = Pressure::vstack(&[&p1, &p2]);
+    /// p.push_row(&(dvector![7.0, 8.0] * BAR));
+    /// assert_relative_eq!(p, dmatrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0; 7.0, 8.0] * BAR);
+    /// ```
+    pub fn vstack(matrices: &[&Self]) -> Self {
+        let ncols = matrices.first().map_or(0, |m| m.0.ncols());
+        assert!(
+            matrices.iter().all(|m| m.0.ncols() == ncols),
+            "All matrices must have the same number of columns."
+        );
+        let nrows = matrices.iter().map(|m| m.0.nrows()).sum();
+        let mut offsets = Vec::with_capacity(nrows);
+        for (k, m) in matrices.iter().enumerate() {
+            offsets.extend((0..m.0.nrows()).map(|i| (k, i)));
+        }
+        Self::new(DMatrix::from_fn(nrows, ncols, |i, j| {
+            let (k, i) = offsets[i];
+            matrices[k].0[(i, j)].clone()
+        }))
+    }
+
+    /// Append a row to the matrix.
+    ///
+    /// **Panics** if the length of the row does not match the number of columns.
+    pub fn push_row(&mut self, row: &Quantity<DVector<T>, U>) {
+        let (nrows, ncols) = self.0.shape();
+        assert_eq!(
+            row.len(),
+            ncols,
+            "The length of the row must match the number of columns."
+        );
+        self.0 = DMatrix::from_fn(nrows + 1, ncols, |i, j| {
+            if i < nrows {
+                self.0[(i, j)].clone()
+            } else {
+                row.0[j].clone()
+            }
+        });
+    }
+
+    /// Append a column to the matrix.
+    ///
+    /// **Panics** if the length of the column does not match the number of rows.
+    pub fn push_column(&mut self, column: &Quantity<DVector<T>, U>) {
+        assert_eq!(
+            column.len(),
+            self.0.nrows(),
+            "The length of the column must match the number of rows."
+        );
+        self.0.extend(column.0.iter().cloned());
+    }
+}
+
+impl<T: Scalar, const N: usize, U> From<[Quantity<T, U>; N]> for Quantity<SVector<T, N>, U> {
+    /// Create a static vector from an array of quantities.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Length, METER};
+    /// # use nalgebra::{vector, Vector3};
+    /// # use approx::assert_relative_eq;
+    /// let x: Length<Vector3<f64>> = [METER, 2.0 * METER, 3.0 * METER].into();
+    /// assert_relative_eq!(x, vector![1.0, 2.0, 3.0] * METER);
+    /// ```
+    fn from(array: [Quantity<T, U>; N]) -> Self {
+        Quantity::new(SVector::from(array.map(|x| x.0)))
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T: Scalar, U> From<Quantity<ndarray::Array1<T>, U>> for Quantity<DVector<T>, U> {
+    /// Convert a quantity array into a quantity vector.
+    ///
+    /// The data is not copied if the array is contiguous.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Length, METER};
+    /// # use ndarray::{arr1, Array1};
+    /// # use nalgebra::{dvector, DVector};
+    /// # use approx::assert_relative_eq;
+    /// let x: Length<Array1<f64>> = arr1(&[1.0, 2.0, 3.0]) * METER;
+    /// let y: Length<DVector<f64>> = x.into();
+    /// assert_relative_eq!(y, dvector![1.0, 2.0, 3.0] * METER);
+    /// let z: Length<Array1<f64>> = y.into();
+    /// assert_relative_eq!(z, arr1(&[1.0, 2.0, 3.0]) * METER);
+    /// ```
+    fn from(array: Quantity<ndarray::Array1<T>, U>) -> Self {
+        let array = array.0;
+        let n = array.len();
+        if array.is_standard_layout() {
+            let (mut values, offset) = array.into_raw_vec_and_offset();
+            let offset = offset.unwrap_or(0);
+            values.truncate(offset + n);
+            values.drain(..offset);
+            Quantity::new(DVector::from_vec(values))
+        } else {
+            Quantity::new(DVector::from_iterator(n, array))
+        }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T: Scalar, U> From<Quantity<DVector<T>, U>> for Quantity<ndarray::Array1<T>, U> {
+    /// Convert a quantity vector into a quantity array without copying the data.
+    fn from(vector: Quantity<DVector<T>, U>) -> Self {
+        let values: Vec<T> = vector.0.data.into();
+        Quantity::new(ndarray::Array1::from_vec(values))
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T: Scalar, U> From<Quantity<ndarray::Array2<T>, U>> for Quantity<DMatrix<T>, U> {
+    /// Convert a two-dimensional quantity array into a quantity matrix.
+    ///
+    /// The data is not copied if the array is contiguous in column-major (Fortran) order.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Pressure, BAR};
+    /// # use ndarray::{arr2, Array2};
+    /// # use nalgebra::{dmatrix, DMatrix};
+    /// # use approx::assert_relative_eq;
+    /// let p: Pressure<Array2<f64>> = arr2(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]) * BAR;
+    /// let q: Pressure<DMatrix<f64>> = p.into();
+    /// assert_relative_eq!(q, dmatrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0] * BAR);
+    /// let r: Pressure<Array2<f64>> = q.into();
+    /// assert_relative_eq!(r, arr2(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]) * BAR);
+    /// ```
+    fn from(array: Quantity<ndarray::Array2<T>, U>) -> Self {
+        let array = array.0;
+        let (nrows, ncols) = array.dim();
+        if array.t().is_standard_layout() {
+            let (mut values, offset) = array.into_raw_vec_and_offset();
+            let offset = offset.unwrap_or(0);
+            values.truncate(offset + nrows * ncols);
+            values.drain(..offset);
+            Quantity::new(DMatrix::from_vec(nrows, ncols, values))
+        } else {
+            Quantity::new(DMatrix::from_fn(nrows, ncols, |i, j| array[(i, j)].clone()))
+        }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T: Scalar, U> From<Quantity<DMatrix<T>, U>> for Quantity<ndarray::Array2<T>, U> {
+    /// Convert a quantity matrix into a two-dimensional quantity array in column-major
+    /// (Fortran) order without copying the data.
+    fn from(matrix: Quantity<DMatrix<T>, U>) -> Self {
+        use ndarray::ShapeBuilder;
+        let shape = matrix.0.shape();
+        let values: Vec<T> = matrix.0.data.into();
+        Quantity::new(ndarray::Array2::from_shape_vec(shape.f(), values).unwrap())
+    }
+}
+
+#[cfg(all(test, feature = "ndarray"))]
+mod tests {
+    use super::*;
+    use crate::{KELVIN, Temperature};
+    use ndarray::{Array1, Array2, arr2, s};
+
+    #[test]
+    fn test_array_conversion() {
+        let a = Array1::linspace(1.0, 10.0, 10) * KELVIN;
+        let ptr = a.0.as_ptr();
+        let v: Temperature<DVector<f64>> = a.into();
+        assert_eq!(v.0.as_ptr(), ptr);
+
+        let b: Temperature<Array1<f64>> = Quantity::new(Array1::linspace(1.0, 10.0, 10));
+        let sliced = Quantity::new(b.0.slice_move(s![2..5]));
+        let v: Temperature<DVector<f64>> = sliced.into();
+        assert_eq!(v.0.as_slice(), &[3.0, 4.0, 5.0]);
+        let strided: Temperature<Array1<f64>> =
+            Quantity::new(Array1::linspace(1.0, 10.0, 10).slice_move(s![..;3]));
+        let v: Temperature<DVector<f64>> = strided.into();
+        assert_eq!(v.0.as_slice(), &[1.0, 4.0, 7.0, 10.0]);
+
+        let m = arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]) * KELVIN;
+        let d: Temperature<DMatrix<f64>> = m.into();
+        assert_eq!(d.0[(2, 1)], 6.0);
+        let ptr = d.0.as_ptr();
+        let m: Temperature<Array2<f64>> = d.into();
+        assert_eq!(m.0.as_ptr(), ptr);
+        assert_eq!(m.0[(2, 0)], 5.0);
+        let d: Temperature<DMatrix<f64>> = m.into();
+        assert_eq!(d.0.as_ptr(), ptr);
+    }
 }
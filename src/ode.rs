@@ -0,0 +1,414 @@
+//! Explicit Runge-Kutta integrators for ordinary differential equations.
+//!
+//! The integrators solve initial value problems $\frac{\mathrm{d}y}{\mathrm{d}t}=f(t,y)$,
+//! where the time $t$ is a `Quantity<f64, UT>` and the state $y$ is a `Quantity<T, UX>`.
+//! The right-hand side has to return a `Quantity<T, Diff<UX, UT>>`, so that the units of
+//! rate equations are checked at compile time. The inner type `T` of the state can be any
+//! type that implements [OdeState], i.e., `f64`, arrays of the `ndarray` crate or matrices of
+//! the `nalgebra` crate.
+//!
+//! # Example
+//! ```
+//! # use quantity::*;
+//! # use quantity::ode::dormand_prince;
+//! # use approx::assert_relative_eq;
+//! // heat-up of a body with a heat transfer coefficient alpha
+//! let (alpha, c_p) = (20.0 * WATT / KELVIN, 4.0 * KILO * JOULE / KELVIN);
+//! let t_env = 350.0 * KELVIN;
+//! let heat_up = |_: Time, t: &Temperature| alpha * (t_env - *t) / c_p;
+//! let sol = dormand_prince(
+//!     heat_up,
+//!     0.0 * SECOND,
+//!     1.0 * HOUR,
+//!     300.0 * KELVIN,
+//!     1e-8,
+//!     1e-8 * KELVIN,
+//!     1000,
+//! )
+//! .unwrap();
+//! let t_end = t_env - 50.0 * KELVIN * (-(alpha / c_p * HOUR).into_value()).exp();
+//! assert_relative_eq!(*sol.y.last().unwrap(), t_end, max_relative = 1e-8 * KELVIN);
+//! ```
+use super::{Diff, Quantity};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DefaultAllocator, Dim, OMatrix, allocator::Allocator};
+#[cfg(feature = "ndarray")]
+use ndarray::{Array, Dimension, Zip};
+use std::fmt;
+use std::ops::Sub;
+
+/// Error type for the adaptive integrators in this module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OdeError {
+    /// The maximum number of steps was reached.
+    MaxSteps,
+    /// The step size became too small compared to the time.
+    StepSizeTooSmall,
+}
+
+impl fmt::Display for OdeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MaxSteps => write!(f, "The maximum number of steps was reached."),
+            Self::StepSizeTooSmall => write!(f, "The step size became too small."),
+        }
+    }
+}
+
+impl std::error::Error for OdeError {}
+
+/// Inner type of the state of an ordinary differential equation.
+pub trait OdeState: Clone {
+    /// Return `c * self`.
+    fn scale(&self, c: f64) -> Self;
+
+    /// Return `self + sum(c_i * k_i)` for all `(c_i, k_i)` in `terms`.
+    fn add_scaled(&self, terms: &[(f64, &Self)]) -> Self;
+
+    /// Root mean square of the elements of `self`, each divided by
+    /// `atol + rtol * max(|y0_i|, |y1_i|)`.
+    fn error_norm(&self, y0: &Self, y1: &Self, atol: f64, rtol: f64) -> f64;
+}
+
+impl OdeState for f64 {
+    fn scale(&self, c: f64) -> Self {
+        c * self
+    }
+
+    fn add_scaled(&self, terms: &[(f64, &Self)]) -> Self {
+        terms.iter().fold(*self, |acc, &(c, k)| acc + c * k)
+    }
+
+    fn error_norm(&self, y0: &Self, y1: &Self, atol: f64, rtol: f64) -> f64 {
+        (self / (atol + rtol * y0.abs().max(y1.abs()))).abs()
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<D: Dimension> OdeState for Array<f64, D> {
+    fn scale(&self, c: f64) -> Self {
+        self * c
+    }
+
+    fn add_scaled(&self, terms: &[(f64, &Self)]) -> Self {
+        let mut res = self.clone();
+        terms.iter().for_each(|&(c, k)| res.scaled_add(c, k));
+        res
+    }
+
+    fn error_norm(&self, y0: &Self, y1: &Self, atol: f64, rtol: f64) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        let sum = Zip::from(self).and(y0).and(y1).fold(0.0, |acc, e, a, b| {
+            acc + (e / (atol + rtol * a.abs().max(b.abs()))).powi(2)
+        });
+        (sum / self.len() as f64).sqrt()
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<R: Dim, C: Dim> OdeState for OMatrix<f64, R, C>
+where
+    DefaultAllocator: Allocator<R, C>,
+{
+    fn scale(&self, c: f64) -> Self {
+        self * c
+    }
+
+    fn add_scaled(&self, terms: &[(f64, &Self)]) -> Self {
+        let mut res = self.clone();
+        terms
+            .iter()
+            .for_each(|&(c, k)| res.zip_apply(k, |r, k| *r += c * k));
+        res
+    }
+
+    fn error_norm(&self, y0: &Self, y1: &Self, atol: f64, rtol: f64) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        let sum: f64 = self
+            .iter()
+            .zip(y0.iter().zip(y1.iter()))
+            .map(|(e, (a, b))| (e / (atol + rtol * a.abs().max(b.abs()))).powi(2))
+            .sum();
+        (sum / self.len() as f64).sqrt()
+    }
+}
+
+/// Times and states of the solution of an ordinary differential equation.
+#[derive(Clone)]
+pub struct Trajectory<UT, T, UX> {
+    /// The times at the accepted steps, including the initial and final time.
+    pub t: Vec<Quantity<f64, UT>>,
+    /// The states at the accepted steps, including the initial and final state.
+    pub y: Vec<Quantity<T, UX>>,
+    /// The number of evaluations of the right-hand side.
+    pub function_evaluations: usize,
+    /// The number of rejected steps.
+    pub rejected_steps: usize,
+}
+
+impl<UT, T: Clone, UX> Trajectory<UT, T, UX> {
+    fn new(t: f64, y: &T) -> Self {
+        Self {
+            t: vec![Quantity::new(t)],
+            y: vec![Quantity::new(y.clone())],
+            function_evaluations: 0,
+            rejected_steps: 0,
+        }
+    }
+
+    fn push(&mut self, t: f64, y: &T) {
+        self.t.push(Quantity::new(t));
+        self.y.push(Quantity::new(y.clone()));
+    }
+}
+
+/// Integrate an ordinary differential equation from `t0` to `t1` with `steps` steps of the
+/// classical fourth-order Runge-Kutta method.
+///
+/// # Example
+/// ```
+/// # use quantity::*;
+/// # use quantity::ode::rk4;
+/// # use approx::assert_relative_eq;
+/// // first-order decay of a concentration
+/// let k = 0.1 / MINUTE;
+/// let decay = |_: Time, c: &Moles| -k * *c;
+/// let sol = rk4(decay, 0.0 * MINUTE, 10.0 * MINUTE, 2.0 * MOL, 100);
+/// assert_eq!(sol.y.len(), 101);
+/// assert_relative_eq!(sol.y[100], 2.0 * MOL * (-1.0f64).exp(), max_relative = 1e-10 * MOL);
+/// ```
+pub fn rk4<F, T, UT, UX>(
+    mut f: F,
+    t0: Quantity<f64, UT>,
+    t1: Quantity<f64, UT>,
+    y0: Quantity<T, UX>,
+    steps: usize,
+) -> Trajectory<UT, T, UX>
+where
+    F: FnMut(Quantity<f64, UT>, &Quantity<T, UX>) -> Quantity<T, Diff<UX, UT>>,
+    T: OdeState,
+    UX: Sub<UT>,
+{
+    let mut f = |t: f64, y: T| f(Quantity::new(t), &Quantity::new(y)).0;
+    let (t0, t1, mut y) = (t0.0, t1.0, y0.0);
+    let mut trajectory = Trajectory::new(t0, &y);
+    let h = (t1 - t0) / steps as f64;
+    for i in 0..steps {
+        let t = t0 + i as f64 * h;
+        let k1 = f(t, y.clone());
+        let k2 = f(t + 0.5 * h, y.add_scaled(&[(0.5 * h, &k1)]));
+        let k3 = f(t + 0.5 * h, y.add_scaled(&[(0.5 * h, &k2)]));
+        let k4 = f(t + h, y.add_scaled(&[(h, &k3)]));
+        y = y.add_scaled(&[
+            (h / 6.0, &k1),
+            (h / 3.0, &k2),
+            (h / 3.0, &k3),
+            (h / 6.0, &k4),
+        ]);
+        trajectory.push(t0 + (i + 1) as f64 * h, &y);
+    }
+    trajectory.function_evaluations = 4 * steps;
+    trajectory
+}
+
+/// Integrate an ordinary differential equation from `t0` to `t1` with the adaptive
+/// fifth-order Dormand-Prince method.
+///
+/// The step size is controlled such that the estimated local error of every element `i` of
+/// the state is below `atol + rtol * |y_i|`. Errors if more than `max_steps` steps are
+/// required or if the step size becomes too small.
+pub fn dormand_prince<F, T, UT, UX>(
+    mut f: F,
+    t0: Quantity<f64, UT>,
+    t1: Quantity<f64, UT>,
+    y0: Quantity<T, UX>,
+    rtol: f64,
+    atol: Quantity<f64, UX>,
+    max_steps: usize,
+) -> Result<Trajectory<UT, T, UX>, OdeError>
+where
+    F: FnMut(Quantity<f64, UT>, &Quantity<T, UX>) -> Quantity<T, Diff<UX, UT>>,
+    T: OdeState,
+    UX: Sub<UT>,
+{
+    let mut f = |t: f64, y: T| f(Quantity::new(t), &Quantity::new(y)).0;
+    let (t0, t1, mut y, atol) = (t0.0, t1.0, y0.0, atol.0);
+    let mut trajectory = Trajectory::new(t0, &y);
+    if t0 == t1 {
+        return Ok(trajectory);
+    }
+    let direction = (t1 - t0).signum();
+
+    // initial step size according to Hairer, Nørsett and Wanner (1993)
+    let mut k1 = f(t0, y.clone());
+    let d0 = y.error_norm(&y, &y, atol, rtol);
+    let d1 = k1.error_norm(&y, &y, atol, rtol);
+    let h0 = if d0 < 1e-5 || d1 < 1e-5 {
+        1e-6
+    } else {
+        0.01 * d0 / d1
+    };
+    let k = f(t0 + direction * h0, y.add_scaled(&[(direction * h0, &k1)]));
+    let d2 = k.add_scaled(&[(-1.0, &k1)]).error_norm(&y, &y, atol, rtol) / h0;
+    let h1 = if d1.max(d2) <= 1e-15 {
+        (h0 * 1e-3).max(1e-6)
+    } else {
+        (0.01 / d1.max(d2)).powf(0.2)
+    };
+    let mut h = (100.0 * h0).min(h1).min((t1 - t0).abs());
+    trajectory.function_evaluations = 2;
+
+    let mut t = t0;
+    let mut rejected = false;
+    for _ in 0..max_steps {
+        if h < 10.0 * f64::EPSILON * t.abs() {
+            return Err(OdeError::StepSizeTooSmall);
+        }
+        let last_step = h >= (t1 - t).abs();
+        let dt = if last_step { t1 - t } else { direction * h };
+
+        let k2 = f(t + dt / 5.0, y.add_scaled(&[(dt / 5.0, &k1)]));
+        let k3 = f(
+            t + 3.0 / 10.0 * dt,
+            y.add_scaled(&[(3.0 / 40.0 * dt, &k1), (9.0 / 40.0 * dt, &k2)]),
+        );
+        let k4 = f(
+            t + 4.0 / 5.0 * dt,
+            y.add_scaled(&[
+                (44.0 / 45.0 * dt, &k1),
+                (-56.0 / 15.0 * dt, &k2),
+                (32.0 / 9.0 * dt, &k3),
+            ]),
+        );
+        let k5 = f(
+            t + 8.0 / 9.0 * dt,
+            y.add_scaled(&[
+                (19372.0 / 6561.0 * dt, &k1),
+                (-25360.0 / 2187.0 * dt, &k2),
+                (64448.0 / 6561.0 * dt, &k3),
+                (-212.0 / 729.0 * dt, &k4),
+            ]),
+        );
+        let k6 = f(
+            t + dt,
+            y.add_scaled(&[
+                (9017.0 / 3168.0 * dt, &k1),
+                (-355.0 / 33.0 * dt, &k2),
+                (46732.0 / 5247.0 * dt, &k3),
+                (49.0 / 176.0 * dt, &k4),
+                (-5103.0 / 18656.0 * dt, &k5),
+            ]),
+        );
+        let y_new = y.add_scaled(&[
+            (35.0 / 384.0 * dt, &k1),
+            (500.0 / 1113.0 * dt, &k3),
+            (125.0 / 192.0 * dt, &k4),
+            (-2187.0 / 6784.0 * dt, &k5),
+            (11.0 / 84.0 * dt, &k6),
+        ]);
+        let k7 = f(t + dt, y_new.clone());
+        trajectory.function_evaluations += 6;
+
+        // difference between the fifth- and fourth-order solutions
+        let error = k1.scale(71.0 / 57600.0 * dt).add_scaled(&[
+            (-71.0 / 16695.0 * dt, &k3),
+            (71.0 / 1920.0 * dt, &k4),
+            (-17253.0 / 339200.0 * dt, &k5),
+            (22.0 / 525.0 * dt, &k6),
+            (-1.0 / 40.0 * dt, &k7),
+        ]);
+        let error = error.error_norm(&y, &y_new, atol, rtol);
+
+        if error <= 1.0 {
+            t = if last_step { t1 } else { t + dt };
+            y = y_new;
+            k1 = k7;
+            trajectory.push(t, &y);
+            if last_step {
+                return Ok(trajectory);
+            }
+            let factor = (0.9 * error.powf(-0.2)).min(10.0);
+            h *= if rejected { factor.min(1.0) } else { factor };
+            rejected = false;
+        } else {
+            h *= (0.9 * error.powf(-0.2)).max(0.2);
+            trajectory.rejected_steps += 1;
+            rejected = true;
+        }
+    }
+    Err(OdeError::MaxSteps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KELVIN, SECOND, Time};
+
+    #[test]
+    fn test_scalar() {
+        let f = |t: Time, _: &crate::Temperature| t * KELVIN / (SECOND * SECOND);
+        let sol = rk4(f, 0.0 * SECOND, 2.0 * SECOND, 1.0 * KELVIN, 2);
+        assert_eq!(sol.function_evaluations, 8);
+        assert!((sol.y[2].0 - 3.0).abs() < 1e-14);
+        let sol = dormand_prince(
+            f,
+            2.0 * SECOND,
+            0.0 * SECOND,
+            3.0 * KELVIN,
+            1e-10,
+            1e-10 * KELVIN,
+            100,
+        )
+        .unwrap();
+        assert_eq!(sol.t.last().unwrap().0, 0.0);
+        assert!((sol.y.last().unwrap().0 - 1.0).abs() < 1e-12);
+        let sol = dormand_prince(
+            f,
+            0.0 * SECOND,
+            2.0 * SECOND,
+            1.0 * KELVIN,
+            1e-10,
+            1e-10 * KELVIN,
+            1,
+        );
+        assert_eq!(sol.err(), Some(OdeError::MaxSteps));
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_ndarray() {
+        use crate::{Length, METER};
+        use ndarray::{Array1, arr1};
+        // harmonic oscillator with an angular frequency of 1/s
+        let f = |_: Time, y: &Length<Array1<f64>>| Quantity::new(arr1(&[y.0[1], -y.0[0]]));
+        let y0 = arr1(&[1.0, 0.0]) * METER;
+        let t1 = std::f64::consts::PI * SECOND;
+        let sol =
+            dormand_prince(f, 0.0 * SECOND, t1, y0.clone(), 1e-10, 1e-10 * METER, 1000).unwrap();
+        let y = sol.y.last().unwrap();
+        assert!((y.0[0] + 1.0).abs() < 1e-8);
+        assert!(y.0[1].abs() < 1e-8);
+        assert!(sol.t.windows(2).all(|t| t[1].0 > t[0].0));
+        let sol = rk4(f, 0.0 * SECOND, t1, y0, 1000);
+        let y = sol.y.last().unwrap();
+        assert!((y.0[0] + 1.0).abs() < 1e-10);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_nalgebra() {
+        use crate::{Length, METER};
+        use nalgebra::{SVector, vector};
+        let f = |_: Time, y: &Length<SVector<f64, 2>>| Quantity::new(vector![y.0[1], -y.0[0]]);
+        let y0 = Length::new(vector![1.0, 0.0]);
+        let t1 = std::f64::consts::PI * SECOND;
+        let sol = dormand_prince(f, 0.0 * SECOND, t1, y0, 1e-10, 1e-10 * METER, 1000).unwrap();
+        let y = sol.y.last().unwrap();
+        assert!((y.0[0] + 1.0).abs() < 1e-8);
+        assert!(y.0[1].abs() < 1e-8);
+    }
+}
@@ -1,10 +1,10 @@
 use super::{Const, Diff, Negate, Prod, Quantity, Quot, Sum};
 #[cfg(feature = "approx")]
-use approx::{AbsDiffEq, RelativeEq};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 #[cfg(feature = "nalgebra")]
 use nalgebra::allocator::Allocator;
 #[cfg(feature = "nalgebra")]
-use nalgebra::{DefaultAllocator, Dim, OMatrix};
+use nalgebra::{DefaultAllocator, Dim, OMatrix, Point};
 #[cfg(feature = "ndarray")]
 use ndarray::{Array, ArrayBase, Data, DataMut, DataOwned, Dimension};
 #[cfg(feature = "num-dual")]
@@ -126,6 +126,22 @@ where
     }
 }
 
+#[cfg(feature = "nalgebra")]
+impl<U, const D: usize> Mul<Quantity<f64, U>> for &Point<f64, D> {
+    type Output = Quantity<Point<f64, D>, U>;
+    fn mul(self, other: Quantity<f64, U>) -> Self::Output {
+        Quantity::new(self * other.0)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<U, const D: usize> Mul<Quantity<f64, U>> for Point<f64, D> {
+    type Output = Quantity<Point<f64, D>, U>;
+    fn mul(self, other: Quantity<f64, U>) -> Self::Output {
+        Quantity::new(self * other.0)
+    }
+}
+
 impl<U, T1, T2> MulAssign<T2> for Quantity<T1, U>
 where
     T1: MulAssign<T2>,
@@ -599,23 +615,40 @@ impl<T: PartialOrd, U> PartialOrd for Quantity<T, U> {
     }
 }
 
+/// Approximate comparisons through the [approx] crate.
+///
+/// The tolerances `epsilon` and `max_relative` are quantities with the same unit as
+/// the compared values. For `max_relative`, the unit is only used for type checking.
+///
+/// # Example
+/// ```
+/// # use quantity::{ANGSTROM, BAR, KILO, PASCAL};
+/// # use approx::{abs_diff_eq, assert_abs_diff_eq, assert_relative_eq};
+/// let p1 = 100.0 * KILO * PASCAL;
+/// let p2 = 100.0005 * KILO * PASCAL;
+/// assert_abs_diff_eq!(p1, p2, epsilon = 1.0 * PASCAL);
+/// assert_relative_eq!(p1, p2, max_relative = 1e-5 * BAR);
+///
+/// let (d1, d2) = (1.0 * ANGSTROM, 2.0 * ANGSTROM);
+/// assert!(!abs_diff_eq!(d1, d2, epsilon = 1e-3 * ANGSTROM));
+/// ```
 #[cfg(feature = "approx")]
 impl<T: AbsDiffEq, U> AbsDiffEq for Quantity<T, U> {
-    type Epsilon = T::Epsilon;
+    type Epsilon = Quantity<T::Epsilon, U>;
 
     fn default_epsilon() -> Self::Epsilon {
-        T::default_epsilon()
+        Quantity::new(T::default_epsilon())
     }
 
     fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
-        self.0.abs_diff_eq(&other.0, epsilon)
+        self.0.abs_diff_eq(&other.0, epsilon.0)
     }
 }
 
 #[cfg(feature = "approx")]
 impl<T: RelativeEq, U> RelativeEq for Quantity<T, U> {
     fn default_max_relative() -> Self::Epsilon {
-        T::default_max_relative()
+        Quantity::new(T::default_max_relative())
     }
 
     fn relative_eq(
@@ -624,6 +657,17 @@ impl<T: RelativeEq, U> RelativeEq for Quantity<T, U> {
         epsilon: Self::Epsilon,
         max_relative: Self::Epsilon,
     ) -> bool {
-        self.0.relative_eq(&other.0, epsilon, max_relative)
+        self.0.relative_eq(&other.0, epsilon.0, max_relative.0)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T: UlpsEq, U> UlpsEq for Quantity<T, U> {
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, epsilon.0, max_ulps)
     }
 }
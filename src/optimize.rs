@@ -0,0 +1,424 @@
+//! Root finding and minimization of scalar functions of quantities.
+//!
+//! The solvers take closures that map a quantity with unit `UX` to a quantity with unit `UY`.
+//! Brackets and tolerances are specified as quantities with unit `UX`, so that no units have
+//! to be stripped before calling a solver.
+//!
+//! # Example
+//! ```
+//! # use quantity::*;
+//! # use quantity::optimize::brent;
+//! # use approx::assert_relative_eq;
+//! // vapor pressure from a simple Clausius-Clapeyron relation
+//! let p_sat = |t: Temperature| BAR * (10.0 - (3000.0 * KELVIN / t).into_value()).exp();
+//! let t_boil = brent(
+//!     |t| p_sat(t) - BAR,
+//!     250.0 * KELVIN,
+//!     400.0 * KELVIN,
+//!     1e-10 * KELVIN,
+//!     100,
+//! )
+//! .unwrap();
+//! assert_relative_eq!(t_boil.x, 300.0 * KELVIN, max_relative = 1e-12 * KELVIN);
+//! ```
+use super::Quantity;
+#[cfg(feature = "num-dual")]
+use num_dual::Dual64;
+use std::fmt;
+#[cfg(feature = "num-dual")]
+use std::ops::Sub;
+
+/// Error type for the solvers in this module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SolverError {
+    /// The function values at the bounds of the bracket have the same sign.
+    NoSignChange,
+    /// The maximum number of iterations was reached.
+    MaxIterations,
+    /// The derivative of the function vanished during a Newton iteration.
+    ZeroDerivative,
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSignChange => write!(f, "The function does not change sign in the bracket."),
+            Self::MaxIterations => write!(f, "The maximum number of iterations was reached."),
+            Self::ZeroDerivative => write!(f, "The derivative of the function vanished."),
+        }
+    }
+}
+
+impl std::error::Error for SolverError {}
+
+/// Result of a solver with iteration diagnostics.
+#[derive(Clone, Copy)]
+pub struct Solution<UX, UY> {
+    /// The root or minimum.
+    pub x: Quantity<f64, UX>,
+    /// The function value at `x`.
+    pub f: Quantity<f64, UY>,
+    /// The number of iterations of the solver.
+    pub iterations: usize,
+    /// The number of function evaluations.
+    pub function_evaluations: usize,
+}
+
+impl<UX, UY> Solution<UX, UY> {
+    fn new(x: f64, f: f64, iterations: usize, function_evaluations: usize) -> Self {
+        Self {
+            x: Quantity::new(x),
+            f: Quantity::new(f),
+            iterations,
+            function_evaluations,
+        }
+    }
+}
+
+/// Find a root of `f` in the bracket `[a, b]` with the bisection method.
+///
+/// The iteration terminates if the width of the bracket is smaller than `2 * tol`.
+/// Errors if `f(a)` and `f(b)` have the same sign or if the maximum number of iterations
+/// is reached.
+///
+/// # Example
+/// ```
+/// # use quantity::*;
+/// # use quantity::optimize::bisection;
+/// # use approx::assert_relative_eq;
+/// let root = bisection(
+///     |x: Length| x * x - 2.0 * METER * METER,
+///     0.0 * METER,
+///     2.0 * METER,
+///     1e-12 * METER,
+///     100,
+/// )
+/// .unwrap();
+/// assert_relative_eq!(root.x, 2.0f64.sqrt() * METER, max_relative = 1e-12 * METER);
+/// ```
+pub fn bisection<F, UX, UY>(
+    f: F,
+    a: Quantity<f64, UX>,
+    b: Quantity<f64, UX>,
+    tol: Quantity<f64, UX>,
+    max_iter: usize,
+) -> Result<Solution<UX, UY>, SolverError>
+where
+    F: Fn(Quantity<f64, UX>) -> Quantity<f64, UY>,
+{
+    let f = |x| f(Quantity::new(x)).0;
+    let (mut a, mut b, tol) = (a.0, b.0, tol.0);
+    let (mut fa, fb) = (f(a), f(b));
+    if fa == 0.0 {
+        return Ok(Solution::new(a, fa, 0, 2));
+    }
+    if fb == 0.0 {
+        return Ok(Solution::new(b, fb, 0, 2));
+    }
+    if fa.signum() == fb.signum() {
+        return Err(SolverError::NoSignChange);
+    }
+    for i in 1..=max_iter {
+        let m = 0.5 * (a + b);
+        let fm = f(m);
+        if fm == 0.0 || 0.5 * (b - a).abs() < tol {
+            return Ok(Solution::new(m, fm, i, i + 2));
+        }
+        if fm.signum() == fa.signum() {
+            (a, fa) = (m, fm);
+        } else {
+            b = m;
+        }
+    }
+    Err(SolverError::MaxIterations)
+}
+
+/// Find a root of `f` in the bracket `[a, b]` with Brent's method.
+///
+/// Brent's method combines bisection with the secant method and inverse quadratic
+/// interpolation. The iteration terminates if the root is determined with an absolute
+/// tolerance `tol`. Errors if `f(a)` and `f(b)` have the same sign or if the maximum number
+/// of iterations is reached.
+pub fn brent<F, UX, UY>(
+    f: F,
+    a: Quantity<f64, UX>,
+    b: Quantity<f64, UX>,
+    tol: Quantity<f64, UX>,
+    max_iter: usize,
+) -> Result<Solution<UX, UY>, SolverError>
+where
+    F: Fn(Quantity<f64, UX>) -> Quantity<f64, UY>,
+{
+    let f = |x| f(Quantity::new(x)).0;
+    let (mut a, mut b, tol) = (a.0, b.0, tol.0);
+    let (mut fa, mut fb) = (f(a), f(b));
+    if fa == 0.0 {
+        return Ok(Solution::new(a, fa, 0, 2));
+    }
+    if fa.signum() == fb.signum() && fb != 0.0 {
+        return Err(SolverError::NoSignChange);
+    }
+    let (mut c, mut fc) = (b, fb);
+    let mut d = b - a;
+    let mut e = d;
+    for i in 1..=max_iter {
+        if fb.signum() == fc.signum() {
+            (c, fc) = (a, fa);
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            (a, fa) = (b, fb);
+            (b, fb) = (c, fc);
+            (c, fc) = (a, fa);
+        }
+        let tol1 = 2.0 * f64::EPSILON * b.abs() + 0.5 * tol;
+        let xm = 0.5 * (c - b);
+        if xm.abs() <= tol1 || fb == 0.0 {
+            return Ok(Solution::new(b, fb, i, i + 1));
+        }
+        if e.abs() >= tol1 && fa.abs() > fb.abs() {
+            // secant method or inverse quadratic interpolation
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * xm * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * xm * q * (q - r) - (b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            }
+            p = p.abs();
+            if 2.0 * p < (3.0 * xm * q - (tol1 * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = xm;
+                e = d;
+            }
+        } else {
+            d = xm;
+            e = d;
+        }
+        (a, fa) = (b, fb);
+        b += if d.abs() > tol1 { d } else { tol1.copysign(xm) };
+        fb = f(b);
+    }
+    Err(SolverError::MaxIterations)
+}
+
+/// Find a root of `f` with Newton's method starting from `x0`.
+///
+/// The derivatives are calculated with automatic differentiation using
+/// [first_derivative](crate::ad::first_derivative). The iteration terminates if the
+/// Newton step is smaller than `tol`. Errors if the derivative vanishes or if the maximum
+/// number of iterations is reached.
+///
+/// # Example
+/// ```
+/// # use quantity::*;
+/// # use quantity::optimize::newton;
+/// # use num_dual::DualNum;
+/// # use approx::assert_relative_eq;
+/// let root = newton(
+///     |x: Length<_>| x * x - 2.0 * METER * METER,
+///     1.0 * METER,
+///     1e-12 * METER,
+///     100,
+/// )
+/// .unwrap();
+/// assert_relative_eq!(root.x, 2.0f64.sqrt() * METER, max_relative = 1e-12 * METER);
+/// ```
+#[cfg(feature = "num-dual")]
+pub fn newton<G, UX, UY>(
+    g: G,
+    x0: Quantity<f64, UX>,
+    tol: Quantity<f64, UX>,
+    max_iter: usize,
+) -> Result<Solution<UX, UY>, SolverError>
+where
+    G: Fn(Quantity<Dual64, UX>) -> Quantity<Dual64, UY>,
+    UY: Sub<UX>,
+{
+    let (mut x, tol) = (x0.0, tol.0);
+    let mut step = f64::INFINITY;
+    for i in 0..=max_iter {
+        let (f, df) = crate::ad::first_derivative(&g, Quantity::<f64, UX>::new(x));
+        let (f, df) = (f.0, df.0);
+        if f == 0.0 || step.abs() <= tol {
+            return Ok(Solution::new(x, f, i, i + 1));
+        }
+        if df == 0.0 {
+            return Err(SolverError::ZeroDerivative);
+        }
+        step = f / df;
+        x -= step;
+    }
+    Err(SolverError::MaxIterations)
+}
+
+/// Find a local minimum of `f` in the interval `[a, b]` with Brent's method.
+///
+/// Brent's method combines golden-section search with successive parabolic interpolation.
+/// The iteration terminates if the minimum is determined with an absolute tolerance of
+/// approximately `tol`. Errors if the maximum number of iterations is reached.
+///
+/// # Example
+/// ```
+/// # use quantity::*;
+/// # use quantity::optimize::brent_minimize;
+/// # use approx::assert_relative_eq;
+/// // minimum of the Lennard-Jones potential
+/// let (sigma, epsilon) = (3.4 * ANGSTROM, 1.65e-21 * JOULE);
+/// let u = |r: Length| 4.0 * epsilon * ((sigma / r).powi::<12>() - (sigma / r).powi::<6>());
+/// let min = brent_minimize(u, 3.0 * ANGSTROM, 6.0 * ANGSTROM, 1e-8 * ANGSTROM, 100).unwrap();
+/// assert_relative_eq!(min.x, 2.0f64.powf(1.0 / 6.0) * sigma, max_relative = 1e-8 * METER);
+/// assert_relative_eq!(min.f, -epsilon, max_relative = 1e-12 * JOULE);
+/// ```
+pub fn brent_minimize<F, UX, UY>(
+    f: F,
+    a: Quantity<f64, UX>,
+    b: Quantity<f64, UX>,
+    tol: Quantity<f64, UX>,
+    max_iter: usize,
+) -> Result<Solution<UX, UY>, SolverError>
+where
+    F: Fn(Quantity<f64, UX>) -> Quantity<f64, UY>,
+{
+    const CGOLD: f64 = 0.381_966_011_250_105_1;
+    let f = |x| f(Quantity::new(x)).0;
+    let (mut a, mut b, tol) = (a.0.min(b.0), a.0.max(b.0), tol.0);
+    let mut x = a + CGOLD * (b - a);
+    let (mut w, mut v) = (x, x);
+    let mut fx = f(x);
+    let (mut fw, mut fv) = (fx, fx);
+    let (mut d, mut e) = (0.0, 0.0f64);
+    for i in 1..=max_iter {
+        let xm = 0.5 * (a + b);
+        let tol1 = f64::EPSILON.sqrt() * x.abs() + tol / 3.0;
+        let tol2 = 2.0 * tol1;
+        if (x - xm).abs() <= tol2 - 0.5 * (b - a) {
+            return Ok(Solution::new(x, fx, i - 1, i));
+        }
+        let mut golden_section = true;
+        if e.abs() > tol1 {
+            // parabolic interpolation through x, v and w
+            let r = (x - w) * (fx - fv);
+            let mut q = (x - v) * (fx - fw);
+            let mut p = (x - v) * q - (x - w) * r;
+            q = 2.0 * (q - r);
+            if q > 0.0 {
+                p = -p;
+            }
+            q = q.abs();
+            if p.abs() < (0.5 * q * e).abs() && p > q * (a - x) && p < q * (b - x) {
+                e = d;
+                d = p / q;
+                let u = x + d;
+                if u - a < tol2 || b - u < tol2 {
+                    d = tol1.copysign(xm - x);
+                }
+                golden_section = false;
+            }
+        }
+        if golden_section {
+            e = if x >= xm { a - x } else { b - x };
+            d = CGOLD * e;
+        }
+        let u = if d.abs() >= tol1 {
+            x + d
+        } else {
+            x + tol1.copysign(d)
+        };
+        let fu = f(u);
+        if fu <= fx {
+            if u >= x {
+                a = x;
+            } else {
+                b = x;
+            }
+            (v, fv) = (w, fw);
+            (w, fw) = (x, fx);
+            (x, fx) = (u, fu);
+        } else {
+            if u < x {
+                a = u;
+            } else {
+                b = u;
+            }
+            if fu <= fw || w == x {
+                (v, fv) = (w, fw);
+                (w, fw) = (u, fu);
+            } else if fu <= fv || v == x || v == w {
+                (v, fv) = (u, fu);
+            }
+        }
+    }
+    Err(SolverError::MaxIterations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KELVIN, METER, SECOND, Temperature, Time};
+
+    #[test]
+    fn test_brackets() {
+        let f = |x: Time| x * x + SECOND * SECOND;
+        assert_eq!(
+            bisection(f, 0.0 * SECOND, 1.0 * SECOND, 1e-10 * SECOND, 100).err(),
+            Some(SolverError::NoSignChange)
+        );
+        assert_eq!(
+            brent(f, 0.0 * SECOND, 1.0 * SECOND, 1e-10 * SECOND, 100).err(),
+            Some(SolverError::NoSignChange)
+        );
+        let f = |x: Time| x * METER / SECOND - METER;
+        assert_eq!(
+            bisection(f, 0.0 * SECOND, 3.0 * SECOND, 1e-10 * SECOND, 5).err(),
+            Some(SolverError::MaxIterations)
+        );
+        let root = brent(f, 1.0 * SECOND, 3.0 * SECOND, 1e-10 * SECOND, 5).unwrap();
+        assert_eq!(root.x.0, 1.0);
+        assert_eq!(root.iterations, 0);
+    }
+
+    #[test]
+    fn test_brent() {
+        // Example from Brent (1973): x^3 - 2x - 5
+        let f = |t: Temperature| {
+            let t = t.convert_into(KELVIN);
+            (t * t * t - 2.0 * t - 5.0) * KELVIN
+        };
+        let root = brent(f, 2.0 * KELVIN, 3.0 * KELVIN, 1e-14 * KELVIN, 100).unwrap();
+        assert!((root.x.0 - 2.0945514815423265).abs() < 1e-14);
+        assert!(root.iterations < 10);
+        let root = bisection(f, 2.0 * KELVIN, 3.0 * KELVIN, 1e-14 * KELVIN, 100).unwrap();
+        assert!((root.x.0 - 2.0945514815423265).abs() < 1e-14);
+        assert!(root.iterations > 40);
+    }
+
+    #[test]
+    fn test_brent_minimize() {
+        let f = |x: Time| (x - 2.0 * SECOND) * (x - 2.0 * SECOND) + 3.0 * SECOND * SECOND;
+        let min = brent_minimize(f, 5.0 * SECOND, 0.0 * SECOND, 1e-8 * SECOND, 100).unwrap();
+        assert!((min.x.0 - 2.0).abs() < 1e-8);
+        assert!((min.f.0 - 3.0).abs() < 1e-14);
+        assert!(min.function_evaluations < 10);
+    }
+
+    #[cfg(feature = "num-dual")]
+    #[test]
+    fn test_newton() {
+        let f = |x: Time<Dual64>| x * x - 2.0 * SECOND * SECOND;
+        let root = newton(f, 1.0 * SECOND, 1e-12 * SECOND, 100).unwrap();
+        assert!((root.x.0 - 2.0f64.sqrt()).abs() < 1e-14);
+        let root = newton(f, 0.0 * SECOND, 1e-12 * SECOND, 100);
+        assert_eq!(root.err(), Some(SolverError::ZeroDerivative));
+    }
+}
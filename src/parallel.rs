@@ -0,0 +1,176 @@
+//! Parallel iteration over quantity arrays using [rayon].
+use super::Quantity;
+use ndarray::parallel::prelude::*;
+use ndarray::{Array, Array1, ArrayBase, Data, Dimension, Zip};
+use rayon::iter::FromParallelIterator;
+
+impl<T, S: Data<Elem = T>, U, D: Dimension> Quantity<ArrayBase<S, D>, U> {
+    /// Return a parallel iterator over the elements of the array.
+    ///
+    /// Elements are visited in arbitrary order.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Energy, KILO, JOULE};
+    /// # use ndarray::{arr1, Array1};
+    /// # use rayon::prelude::*;
+    /// # use approx::assert_relative_eq;
+    /// let e: Energy<Array1<f64>> = arr1(&[1.0, 2.0, 3.0]) * KILO * JOULE;
+    /// let total = e.par_iter().reduce(|| 0.0 * JOULE, |a, b| a + b);
+    /// assert_relative_eq!(total, 6.0 * KILO * JOULE);
+    /// ```
+    pub fn par_iter(&self) -> impl ParallelIterator<Item = Quantity<T, U>> + '_
+    where
+        T: Clone + Send + Sync,
+        U: Send,
+    {
+        self.0
+            .view()
+            .into_par_iter()
+            .map(|x| Quantity::new(x.clone()))
+    }
+
+    /// Parallel version of [mapv](Quantity::mapv).
+    ///
+    /// Call `f` by value on each element and create a new array with the new values.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Temperature, KELVIN, BAR, MOL, RGAS, METER};
+    /// # use ndarray::{arr1, Array1};
+    /// # use approx::assert_relative_eq;
+    /// let t: Temperature<Array1<f64>> = arr1(&[300.0, 400.0]) * KELVIN;
+    /// let rho = 50.0 * MOL / METER.powi::<3>();
+    /// let p = t.par_mapv(|t| rho * RGAS * t);
+    /// assert_relative_eq!(p, &t * rho * RGAS);
+    /// ```
+    pub fn par_mapv<F, T2, U2>(&self, f: F) -> Quantity<Array<T2, D>, U2>
+    where
+        T: Clone + Sync,
+        T2: Send,
+        F: Fn(Quantity<T, U>) -> Quantity<T2, U2> + Sync + Send,
+    {
+        Quantity::new(Zip::from(&self.0).par_map_collect(|x| f(Quantity::new(x.clone())).0))
+    }
+}
+
+/// Parallel lock step iteration over several quantity arrays with the same shape.
+///
+/// Implemented for tuples of references to (up to 5) quantity arrays with arbitrary units.
+/// The closures receive a tuple of the corresponding elements with their respective units.
+///
+/// **Panics** if the shapes of the arrays are not equal.
+///
+/// # Example
+/// ```
+/// # use quantity::{Pressure, Temperature, ParallelZip, KELVIN, MOL, METER, RGAS};
+/// # use ndarray::{arr1, Array1};
+/// # use approx::assert_relative_eq;
+/// let t: Temperature<Array1<f64>> = arr1(&[300.0, 400.0]) * KELVIN;
+/// let rho = arr1(&[10.0, 20.0]) * MOL / METER.powi::<3>();
+/// let p: Pressure<Array1<f64>> = (&t, &rho).par_map_collect(|(t, rho)| rho * RGAS * t);
+/// assert_relative_eq!(p, &rho * RGAS * &t);
+/// ```
+pub trait ParallelZip<D: Dimension> {
+    /// The tuple of elements that is passed to the closures.
+    type Item;
+
+    /// Call `f` for each tuple of elements and collect the results into a new array with
+    /// the same shape as the inputs.
+    fn par_map_collect<T: Send, U, F>(self, f: F) -> Quantity<Array<T, D>, U>
+    where
+        F: Fn(Self::Item) -> Quantity<T, U> + Sync + Send;
+
+    /// Call `f` for each tuple of elements. Elements are visited in arbitrary order.
+    fn par_for_each<F>(self, f: F)
+    where
+        F: Fn(Self::Item) + Sync + Send;
+}
+
+macro_rules! impl_parallel_zip {
+    ($x0:ident: $t0:ident, $s0:ident, $u0:ident $(; $x:ident: $t:ident, $s:ident, $u:ident)*) => {
+        impl<'a, D: Dimension, $t0, $s0, $u0 $(, $t, $s, $u)*> ParallelZip<D>
+            for (&'a Quantity<ArrayBase<$s0, D>, $u0>, $(&'a Quantity<ArrayBase<$s, D>, $u>,)*)
+        where
+            $t0: Clone + Sync,
+            $s0: Data<Elem = $t0>,
+            $($t: Clone + Sync, $s: Data<Elem = $t>,)*
+        {
+            type Item = (Quantity<$t0, $u0>, $(Quantity<$t, $u>,)*);
+
+            fn par_map_collect<T: Send, U, F>(self, f: F) -> Quantity<Array<T, D>, U>
+            where
+                F: Fn(Self::Item) -> Quantity<T, U> + Sync + Send,
+            {
+                let ($x0, $($x,)*) = self;
+                Quantity::new(Zip::from(&$x0.0)$(.and(&$x.0))*.par_map_collect(|$x0, $($x),*| {
+                    f((Quantity::new($x0.clone()), $(Quantity::new($x.clone()),)*)).0
+                }))
+            }
+
+            fn par_for_each<F>(self, f: F)
+            where
+                F: Fn(Self::Item) + Sync + Send,
+            {
+                let ($x0, $($x,)*) = self;
+                Zip::from(&$x0.0)$(.and(&$x.0))*.par_for_each(|$x0, $($x),*| {
+                    f((Quantity::new($x0.clone()), $(Quantity::new($x.clone()),)*))
+                })
+            }
+        }
+    };
+}
+
+impl_parallel_zip!(x0: T0, S0, U0);
+impl_parallel_zip!(x0: T0, S0, U0; x1: T1, S1, U1);
+impl_parallel_zip!(x0: T0, S0, U0; x1: T1, S1, U1; x2: T2, S2, U2);
+impl_parallel_zip!(x0: T0, S0, U0; x1: T1, S1, U1; x2: T2, S2, U2; x3: T3, S3, U3);
+impl_parallel_zip!(x0: T0, S0, U0; x1: T1, S1, U1; x2: T2, S2, U2; x3: T3, S3, U3; x4: T4, S4, U4);
+
+impl<T: Send, U: Send> IntoParallelIterator for Quantity<Array1<T>, U> {
+    type Item = Quantity<T, U>;
+    type Iter = rayon::iter::Map<rayon::vec::IntoIter<T>, fn(T) -> Quantity<T, U>>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        let values: Vec<_> = self.0.into_iter().collect();
+        values.into_par_iter().map(Quantity::new)
+    }
+}
+
+impl<T: Send, U: Send> FromParallelIterator<Quantity<T, U>> for Quantity<Array1<T>, U> {
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = Quantity<T, U>>,
+    {
+        let values: Vec<_> = par_iter.into_par_iter().map(|x| x.0).collect();
+        Quantity::new(Array1::from_vec(values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KELVIN, METER, Temperature};
+    use ndarray::{Array2, arr1};
+
+    #[test]
+    fn test_into_par_iter() {
+        let t: Temperature<Array1<f64>> = Quantity::linspace(100.0 * KELVIN, 500.0 * KELVIN, 101);
+        let t2: Temperature<Array1<f64>> = t.clone().into_par_iter().map(|t| t * 2.0).collect();
+        assert_eq!(t2.0, &t.0 * 2.0);
+    }
+
+    #[test]
+    fn test_par_zip() {
+        let x = Array2::from_shape_fn((20, 30), |(i, j)| (i * j) as f64) * METER;
+        let y = Array2::from_shape_fn((20, 30), |(i, j)| (i + j) as f64) * KELVIN;
+        let z = (&x, &y, &x).par_map_collect(|(x1, y, x2)| x1 * y / x2);
+        assert_eq!(z.shape(), &[20, 30]);
+        assert_eq!(z.get((3, 4)).0, 7.0);
+        let sum = std::sync::Mutex::new(0.0);
+        (&x, &y).par_for_each(|(x, y)| *sum.lock().unwrap() += (x * y).0);
+        assert_eq!(*sum.lock().unwrap(), (&x * &y).sum().0);
+        let s = (&(arr1(&[1.0, 2.0]) * METER),).par_map_collect(|(x,)| x * x);
+        assert_eq!(s.0, arr1(&[1.0, 4.0]));
+    }
+}
@@ -0,0 +1,265 @@
+//! Random sampling of quantities using the [rand] and [rand_distr] crates.
+//!
+//! Scalar quantities implement [SampleUniform], so that [Uniform](rand::distr::Uniform)
+//! distributions and [random_range](rand::Rng::random_range) can be used directly with
+//! quantity bounds. Additionally, [Normal] and [LogNormal] distributions with
+//! parameters that are quantities are provided.
+//!
+//! # Example
+//! ```
+//! # use quantity::{Temperature, KELVIN, BAR};
+//! # use quantity::random::Normal;
+//! # use rand::distr::{Distribution, Uniform};
+//! # use rand::SeedableRng;
+//! # use rand::rngs::StdRng;
+//! let mut rng = StdRng::seed_from_u64(42);
+//! let t = Uniform::new(290.0 * KELVIN, 310.0 * KELVIN).unwrap();
+//! let p = Normal::new(BAR, 0.01 * BAR).unwrap();
+//! for _ in 0..10 {
+//!     let t = t.sample(&mut rng);
+//!     assert!(t >= 290.0 * KELVIN && t < 310.0 * KELVIN);
+//!     let p = p.sample(&mut rng);
+//!     assert!(p > 0.9 * BAR && p < 1.1 * BAR);
+//! }
+//! ```
+use super::Quantity;
+#[cfg(feature = "ndarray")]
+use ndarray::Array1;
+use rand::Rng;
+use rand::distr::Distribution;
+use rand::distr::uniform::{Error, SampleBorrow, SampleUniform, UniformFloat, UniformSampler};
+use rand_distr::NormalError;
+use std::marker::PhantomData;
+
+/// The back-end implementing uniform sampling of quantities.
+pub struct UniformQuantity<U>(UniformFloat<f64>, PhantomData<U>);
+
+impl<U> Clone for UniformQuantity<U> {
+    fn clone(&self) -> Self {
+        Self(self.0, PhantomData)
+    }
+}
+
+impl<U> UniformSampler for UniformQuantity<U> {
+    type X = Quantity<f64, U>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        UniformFloat::new(low.borrow().0, high.borrow().0).map(|u| Self(u, PhantomData))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        UniformFloat::new_inclusive(low.borrow().0, high.borrow().0).map(|u| Self(u, PhantomData))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Quantity::new(self.0.sample(rng))
+    }
+}
+
+impl<U> SampleUniform for Quantity<f64, U> {
+    type Sampler = UniformQuantity<U>;
+}
+
+/// The normal distribution with a quantity as mean and standard deviation.
+pub struct Normal<U>(rand_distr::Normal<f64>, PhantomData<U>);
+
+impl<U> Clone for Normal<U> {
+    fn clone(&self) -> Self {
+        Self(self.0, PhantomData)
+    }
+}
+
+impl<U> Normal<U> {
+    /// Construct a normal distribution with the given mean and standard deviation.
+    ///
+    /// Returns [NormalError::BadVariance] if the standard deviation is negative or not finite.
+    pub fn new(mean: Quantity<f64, U>, std_dev: Quantity<f64, U>) -> Result<Self, NormalError> {
+        check_non_negative(std_dev.0)?;
+        rand_distr::Normal::new(mean.0, std_dev.0).map(|n| Self(n, PhantomData))
+    }
+
+    /// Return the mean of the distribution.
+    pub fn mean(&self) -> Quantity<f64, U> {
+        Quantity::new(self.0.mean())
+    }
+
+    /// Return the standard deviation of the distribution.
+    pub fn std_dev(&self) -> Quantity<f64, U> {
+        Quantity::new(self.0.std_dev())
+    }
+}
+
+impl<U> Distribution<Quantity<f64, U>> for Normal<U> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quantity<f64, U> {
+        Quantity::new(self.0.sample(rng))
+    }
+}
+
+/// The log-normal distribution of a quantity.
+///
+/// The logarithm of the quantity divided by its median is normally distributed with
+/// mean 0 and standard deviation `sigma`.
+pub struct LogNormal<U>(rand_distr::LogNormal<f64>, PhantomData<U>);
+
+impl<U> Clone for LogNormal<U> {
+    fn clone(&self) -> Self {
+        Self(self.0, PhantomData)
+    }
+}
+
+impl<U> LogNormal<U> {
+    /// Construct a log-normal distribution with the given median and standard deviation
+    /// `sigma` of the logarithm.
+    ///
+    /// Returns [NormalError::MeanTooSmall] if `median` is not positive and finite (including
+    /// NaN and infinity), and [NormalError::BadVariance] if `sigma` is negative or not finite.
+    pub fn new(median: Quantity<f64, U>, sigma: f64) -> Result<Self, NormalError> {
+        check_positive(median.0)?;
+        check_non_negative(sigma)?;
+        rand_distr::LogNormal::new(median.0.ln(), sigma).map(|n| Self(n, PhantomData))
+    }
+
+    /// Construct a log-normal distribution with the given mean and coefficient of
+    /// variation (the ratio of standard deviation and mean).
+    ///
+    /// Returns [NormalError::MeanTooSmall] if `mean` is not positive and finite (including
+    /// NaN and infinity), and [NormalError::BadVariance] if `cv` is negative or not finite.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::BAR;
+    /// # use quantity::random::LogNormal;
+    /// # use rand::distr::Distribution;
+    /// # use rand::SeedableRng;
+    /// # use rand::rngs::StdRng;
+    /// # use approx::assert_relative_eq;
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let dist = LogNormal::from_mean_cv(2.0 * BAR, 0.1).unwrap();
+    /// let n = 10000;
+    /// let mean = (0..n).map(|_| dist.sample(&mut rng).convert_into(BAR)).sum::<f64>() / n as f64;
+    /// assert_relative_eq!(mean, 2.0, max_relative = 1e-2);
+    /// ```
+    pub fn from_mean_cv(mean: Quantity<f64, U>, cv: f64) -> Result<Self, NormalError> {
+        check_positive(mean.0)?;
+        rand_distr::LogNormal::from_mean_cv(mean.0, cv).map(|n| Self(n, PhantomData))
+    }
+}
+
+fn check_positive(x: f64) -> Result<(), NormalError> {
+    if x > 0.0 && x.is_finite() {
+        Ok(())
+    } else {
+        Err(NormalError::MeanTooSmall)
+    }
+}
+
+fn check_non_negative(x: f64) -> Result<(), NormalError> {
+    if x >= 0.0 {
+        Ok(())
+    } else {
+        Err(NormalError::BadVariance)
+    }
+}
+
+impl<U> Distribution<Quantity<f64, U>> for LogNormal<U> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quantity<f64, U> {
+        Quantity::new(self.0.sample(rng))
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<U> Quantity<Array1<f64>, U> {
+    /// Create an array with `n` elements sampled from the distribution `dist`.
+    ///
+    /// # Example
+    /// ```
+    /// # use quantity::{Temperature, KELVIN};
+    /// # use ndarray::Array1;
+    /// # use rand::distr::Uniform;
+    /// # use rand::SeedableRng;
+    /// # use rand::rngs::StdRng;
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let dist = Uniform::new(290.0 * KELVIN, 310.0 * KELVIN).unwrap();
+    /// let t = Temperature::<Array1<f64>>::random(100, dist, &mut rng);
+    /// assert_eq!(t.len(), 100);
+    /// assert!(t.min().unwrap() >= 290.0 * KELVIN);
+    /// assert!(t.max().unwrap() < 310.0 * KELVIN);
+    /// ```
+    pub fn random<Dist, R>(n: usize, dist: Dist, rng: &mut R) -> Self
+    where
+        Dist: Distribution<Quantity<f64, U>>,
+        R: Rng + ?Sized,
+    {
+        dist.sample_iter(rng).take(n).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BAR, KELVIN};
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_uniform() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let t = rng.random_range(300.0 * KELVIN..=310.0 * KELVIN);
+            assert!(t.0 >= 300.0 && t.0 <= 310.0);
+        }
+        assert!(rand::distr::Uniform::new(310.0 * KELVIN, 300.0 * KELVIN).is_err());
+    }
+
+    #[test]
+    fn test_normal() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let dist = Normal::new(5.0 * BAR, 0.5 * BAR).unwrap();
+        assert_eq!(dist.mean().0, 5e5);
+        assert_eq!(dist.std_dev().0, 5e4);
+        let n = 10000;
+        let mean = (0..n).map(|_| dist.sample(&mut rng).0).sum::<f64>() / n as f64;
+        assert!((mean / 5e5 - 1.0).abs() < 1e-2);
+        for std_dev in [-1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                Normal::new(BAR, std_dev * BAR),
+                Err(NormalError::BadVariance)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_log_normal() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let dist = LogNormal::new(2.0 * BAR, 0.5).unwrap();
+        let mut samples: Vec<_> = (0..10001).map(|_| dist.sample(&mut rng).0).collect();
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!((samples[5000] / 2e5 - 1.0).abs() < 5e-2);
+        assert!(samples[0] > 0.0);
+        for median in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                LogNormal::new(median * BAR, 0.5),
+                Err(NormalError::MeanTooSmall)
+            ));
+            assert!(matches!(
+                LogNormal::from_mean_cv(median * BAR, 0.0),
+                Err(NormalError::MeanTooSmall)
+            ));
+        }
+        for sigma in [-0.5, f64::NAN] {
+            assert!(matches!(
+                LogNormal::new(BAR, sigma),
+                Err(NormalError::BadVariance)
+            ));
+        }
+        assert!(LogNormal::from_mean_cv(BAR, -0.1).is_err());
+    }
+}
@@ -0,0 +1,131 @@
+//! State vectors with a different unit for every component.
+//!
+//! Tuples of quantities (with up to 8 elements) implement [StateVector], which allows
+//! converting them to and from an [SVector], either containing the values in SI units or
+//! values that are scaled by a reference state. The scaled vectors are dimensionless and
+//! can be passed to solvers or linear algebra routines that are not unit-aware.
+//!
+//! # Example
+//! ```
+//! # use quantity::*;
+//! # use quantity::state::StateVector;
+//! # use nalgebra::vector;
+//! # use approx::assert_relative_eq;
+//! let state = (300.0 * KELVIN, 2.0 * BAR);
+//! let scale = (100.0 * KELVIN, BAR);
+//! let x = state.to_scaled_vector(&scale);
+//! assert_relative_eq!(x, vector![3.0, 2.0]);
+//!
+//! let (t, p) = <(Temperature, Pressure)>::from_scaled_vector(&(x * 1.5), &scale);
+//! assert_relative_eq!(t, 450.0 * KELVIN);
+//! assert_relative_eq!(p, 3.0 * BAR);
+//! ```
+//!
+//! With the `num-dual` feature, state vectors can be used in `ad::state_gradient` and
+//! `ad::state_jacobian` to calculate derivatives with the correct unit of every entry.
+use super::{Diff, Quantity};
+use nalgebra::{SMatrix, SVector, Scalar};
+use std::ops::{Div, Mul, Sub};
+
+/// A heterogeneous collection of `N` quantities with the same inner type.
+pub trait StateVector<const N: usize>: Sized {
+    /// The inner type of the quantities.
+    type Inner: Scalar;
+
+    /// The state vector with the same units but a different inner type.
+    type Map<T: Scalar>: StateVector<N, Inner = T>;
+
+    /// Return the values of all components in SI units.
+    fn to_vector(&self) -> SVector<Self::Inner, N>;
+
+    /// Create a state from the values of all components in SI units.
+    fn from_vector(vector: &SVector<Self::Inner, N>) -> Self;
+
+    /// Return the dimensionless values of all components divided by the corresponding
+    /// components of `scale`.
+    fn to_scaled_vector(&self, scale: &Self::Map<f64>) -> SVector<Self::Inner, N>
+    where
+        Self::Inner: Div<f64, Output = Self::Inner>,
+    {
+        let (x, scale) = (self.to_vector(), scale.to_vector());
+        SVector::from_fn(|i, _| x[i].clone() / scale[i])
+    }
+
+    /// Create a state from dimensionless values that are multiplied by the corresponding
+    /// components of `scale`.
+    fn from_scaled_vector(vector: &SVector<Self::Inner, N>, scale: &Self::Map<f64>) -> Self
+    where
+        Self::Inner: Mul<f64, Output = Self::Inner>,
+    {
+        let scale = scale.to_vector();
+        Self::from_vector(&SVector::from_fn(|i, _| vector[i].clone() * scale[i]))
+    }
+}
+
+/// The units of the derivatives of a quantity with unit `Self` with respect to the
+/// state `X`.
+pub trait StateGradient<X: StateVector<N>, const N: usize> {
+    /// The tuple of partial derivatives.
+    type Output;
+
+    /// Create the partial derivatives from their values in SI units.
+    fn gradient_from_vector(vector: &SVector<X::Inner, N>) -> Self::Output;
+}
+
+/// The units of the Jacobian of a state `Self` with respect to the state `X`.
+pub trait StateJacobian<X: StateVector<N>, const N: usize, const M: usize> {
+    /// The tuple of rows of the Jacobian, each of which is a tuple of partial derivatives.
+    type Output;
+
+    /// Create the Jacobian from its values in SI units.
+    fn jacobian_from_matrix(matrix: &SMatrix<X::Inner, M, N>) -> Self::Output;
+}
+
+macro_rules! impl_state_vector {
+    ($n:literal; $($u:ident: $i:tt),+) => {
+        impl<T: Scalar, $($u),+> StateVector<$n> for ($(Quantity<T, $u>,)+) {
+            type Inner = T;
+            type Map<T2: Scalar> = ($(Quantity<T2, $u>,)+);
+
+            fn to_vector(&self) -> SVector<T, $n> {
+                SVector::from([$(self.$i.0.clone()),+])
+            }
+
+            fn from_vector(vector: &SVector<T, $n>) -> Self {
+                ($(Quantity::new(vector[$i].clone()),)+)
+            }
+        }
+
+        impl<T: Scalar, UY, $($u),+> StateGradient<($(Quantity<T, $u>,)+), $n> for UY
+        where
+            $(UY: Sub<$u>,)+
+        {
+            type Output = ($(Quantity<T, Diff<UY, $u>>,)+);
+
+            fn gradient_from_vector(vector: &SVector<T, $n>) -> Self::Output {
+                ($(Quantity::new(vector[$i].clone()),)+)
+            }
+        }
+
+        impl<X: StateVector<N>, const N: usize, T, $($u),+> StateJacobian<X, N, $n>
+            for ($(Quantity<T, $u>,)+)
+        where
+            $($u: StateGradient<X, N>,)+
+        {
+            type Output = ($(<$u as StateGradient<X, N>>::Output,)+);
+
+            fn jacobian_from_matrix(matrix: &SMatrix<X::Inner, $n, N>) -> Self::Output {
+                ($($u::gradient_from_vector(&matrix.row($i).transpose()),)+)
+            }
+        }
+    };
+}
+
+impl_state_vector!(1; U0: 0);
+impl_state_vector!(2; U0: 0, U1: 1);
+impl_state_vector!(3; U0: 0, U1: 1, U2: 2);
+impl_state_vector!(4; U0: 0, U1: 1, U2: 2, U3: 3);
+impl_state_vector!(5; U0: 0, U1: 1, U2: 2, U3: 3, U4: 4);
+impl_state_vector!(6; U0: 0, U1: 1, U2: 2, U3: 3, U4: 4, U5: 5);
+impl_state_vector!(7; U0: 0, U1: 1, U2: 2, U3: 3, U4: 4, U5: 5, U6: 6);
+impl_state_vector!(8; U0: 0, U1: 1, U2: 2, U3: 3, U4: 4, U5: 5, U6: 6, U7: 7);